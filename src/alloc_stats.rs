@@ -0,0 +1,125 @@
+//! Counting of heap allocations, to see how much memory solutions use.
+//!
+//! Allocations are only counted if [`Counting`] is the global allocator, which the binary installs with the
+//! `count-allocations` feature. Counts are kept per thread, so that solutions running in parallel do not mix.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::ZERO) };
+}
+
+/// Allocations made while running some code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+
+    /// Total bytes allocated.
+    pub bytes: u64,
+
+    /// Most bytes allocated and not yet freed at any one time.
+    pub peak_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+
+    /// Bytes allocated minus bytes freed, which is negative if more was freed than allocated.
+    live_bytes: i64,
+    peak_bytes: i64,
+}
+impl Counters {
+    const ZERO: Self = Self {
+        allocations: 0,
+        bytes: 0,
+        live_bytes: 0,
+        peak_bytes: 0,
+    };
+
+    fn allocate(&mut self, bytes: usize) {
+        self.allocations += 1;
+        self.bytes += bytes as u64;
+        self.live_bytes += bytes as i64;
+        self.peak_bytes = self.peak_bytes.max(self.live_bytes);
+    }
+
+    fn free(&mut self, bytes: usize) {
+        self.live_bytes -= bytes as i64;
+    }
+}
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        f(&mut c);
+        counters.set(c);
+    });
+}
+
+/// Global allocator that counts allocations on each thread, and otherwise leaves allocating to the system allocator.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        update(|c| c.allocate(layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        update(|c| c.allocate(layout.size()));
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        update(|c| c.free(layout.size()));
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        update(|c| {
+            c.free(layout.size());
+            c.allocate(new_size);
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Run `f` and count the allocations it makes on the current thread.
+///
+/// All counts are zero if [`Counting`] is not the global allocator.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let before = COUNTERS.replace(Counters::ZERO);
+    let result = f();
+    let during = COUNTERS.replace(before);
+    let stats = AllocStats {
+        allocations: during.allocations,
+        bytes: during.bytes,
+        peak_bytes: during.peak_bytes as u64,
+    };
+    update(|c| {
+        c.allocations += during.allocations;
+        c.bytes += during.bytes;
+        c.peak_bytes = c.peak_bytes.max(c.live_bytes + during.peak_bytes);
+        c.live_bytes += during.live_bytes;
+    });
+    (result, stats)
+}
+
+#[test]
+fn test_counters() {
+    let mut counters = Counters::ZERO;
+    counters.allocate(100);
+    counters.allocate(50);
+    counters.free(100);
+    counters.allocate(20);
+    assert_eq!(counters.allocations, 3);
+    assert_eq!(counters.bytes, 170);
+    assert_eq!(counters.live_bytes, 70);
+    assert_eq!(counters.peak_bytes, 150);
+}
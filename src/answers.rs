@@ -0,0 +1,137 @@
+//! Known answers to the puzzles, used to check solutions for regressions.
+//!
+//! Each year has its own answers file, `input/<year>/answers.toml`. It is TOML with a table per day and a key per part:
+//!
+//! ```toml
+//! [day_01]
+//! part_1 = 24000
+//! part_2 = 45000
+//! ```
+
+use crate::input;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Default location of the answers file of a given year.
+pub fn default_path(year: usize) -> PathBuf {
+    input::year_dir(year).join("answers.toml")
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersError {
+    #[error("could not read answers file {path}: {source}")]
+    Read { path: String, source: std::io::Error },
+
+    #[error("could not parse answers file {path}: {source}")]
+    Parse { path: String, source: toml::de::Error },
+
+    #[error("invalid entry `{key}` in answers file {path}: {reason}")]
+    Invalid { path: String, key: String, reason: &'static str },
+}
+
+/// Known answers, indexed by day and part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(usize, usize), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents = std::fs::read_to_string(path).map_err(|source| AnswersError::Read {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::parse(&contents, path)
+    }
+
+    fn parse(s: &str, path: &Path) -> Result<Self, AnswersError> {
+        let table: toml::Table = s.parse().map_err(|source| AnswersError::Parse {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let invalid = |key: &str, reason| AnswersError::Invalid {
+                path: path.display().to_string(),
+                key: key.to_owned(),
+                reason,
+            };
+            let day = parse_key(&day_key, "day_").ok_or_else(|| invalid(&day_key, "expected a table named like `day_01`"))?;
+            let toml::Value::Table(parts) = parts else {
+                return Err(invalid(&day_key, "expected a table"));
+            };
+            for (part_key, answer) in parts {
+                let key = format!("{day_key}.{part_key}");
+                let part = parse_key(&part_key, "part_")
+                    .filter(|part| (1..=2).contains(part))
+                    .ok_or_else(|| invalid(&key, "expected `part_1` or `part_2`"))?;
+                let answer = match answer {
+                    toml::Value::String(s) => s,
+                    toml::Value::Integer(n) => n.to_string(),
+                    _ => return Err(invalid(&key, "expected a string or an integer")),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Known answer for a given day and part, if any.
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// Parse keys like `day_07` into the number 7.
+fn parse_key(key: &str, prefix: &str) -> Option<usize> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Whether an answer matches the known answer.
+///
+/// Leading and trailing whitespace is not significant, as multi-line answers are rendered with surrounding newlines.
+pub fn matches(answer: &str, known_answer: &str) -> bool {
+    answer.trim() == known_answer.trim()
+}
+
+#[test]
+fn test_parse() {
+    let answers = Answers::parse(
+        r#"
+[day_01]
+part_1 = 24000
+part_2 = "45000"
+
+[day_10]
+part_2 = """
+##..
+..##
+"""
+"#,
+        Path::new("answers.toml"),
+    )
+    .unwrap();
+    assert_eq!(answers.get(1, 1), Some("24000"));
+    assert_eq!(answers.get(1, 2), Some("45000"));
+    assert_eq!(answers.get(10, 1), None);
+    assert!(matches("\n##..\n..##\n", answers.get(10, 2).unwrap()));
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(matches!(
+        Answers::parse("[day_01]\npart_3 = 1\n", Path::new("answers.toml")),
+        Err(AnswersError::Invalid { .. })
+    ));
+    assert!(matches!(
+        Answers::parse("[first]\npart_1 = 1\n", Path::new("answers.toml")),
+        Err(AnswersError::Invalid { .. })
+    ));
+    assert!(matches!(
+        Answers::parse("[day_01]\npart_1 = 1.5\n", Path::new("answers.toml")),
+        Err(AnswersError::Invalid { .. })
+    ));
+}
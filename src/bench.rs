@@ -0,0 +1,118 @@
+//! Repeatedly time solutions and summarize the timings.
+
+use crate::input;
+use advent_of_code::solution::AnySolution;
+use colored::Colorize;
+use std::hint::black_box;
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Summary statistics of a number of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// Will panic if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Time parsing the input and solving the given parts of every given solution `runs` times, after `warmup` untimed runs.
+///
+/// Only the solution itself is timed, not reading the input. Parts are solved from one parse of the input.
+/// Days or parts that fail are reported and skipped.
+pub fn bench(solutions: &[&dyn AnySolution], parts: &[usize], warmup: usize, runs: NonZeroUsize) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for solution in solutions {
+        let day = solution.day();
+        let input = match input::read_input(solution.year(), day, None) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{} {err}", "error:".red().bold());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let parse_samples = (0..warmup + runs.get())
+            .map(|_| solution.solve(&input, &[], &[], &mut |_, _, _| {}))
+            .collect::<Result<Vec<Duration>, _>>();
+        match parse_samples {
+            Ok(samples) => print_stats(day, "parse", &samples[warmup..]),
+            Err(err) => {
+                eprintln!("{} Day {day}: {err}", "error:".red().bold());
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        }
+
+        for &part in parts {
+            let mut samples = Vec::new();
+            let mut error = None;
+            let repeated_part = vec![part; warmup + runs.get()];
+            let parsed = solution.solve(&input, &[], &repeated_part, &mut |_, usage, answer| match black_box(answer) {
+                Ok(_) => samples.push(usage.elapsed),
+                Err(err) => error = Some(err),
+            });
+            match parsed.err().or(error) {
+                None => print_stats(day, &format!("Part {part}"), &samples[warmup..]),
+                Some(err) => {
+                    eprintln!("{} Day {day} Part {part}: {err}", "error:".red().bold());
+                    exit_code = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    exit_code
+}
+
+fn print_stats(day: usize, label: &str, samples: &[Duration]) {
+    let Stats { min, median, mean, stddev } = Stats::from_samples(samples);
+    let runs = samples.len();
+    let label = format!("{label}:");
+    println!("Day {day:>2} {label:<8} min {min:>10.2?}  median {median:>10.2?}  mean {mean:>10.2?}  stddev {stddev:>10.2?}  ({runs} runs)");
+}
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+    let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]);
+    assert_eq!(stats.min, ms(2));
+    assert_eq!(stats.median, ms(5));
+    assert_eq!(stats.mean, ms(5));
+    assert_eq!(stats.stddev.as_micros(), 2236);
+
+    let stats = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+    assert_eq!(stats.median, ms(2));
+}
@@ -0,0 +1,64 @@
+//! Cooperative cancellation of solutions that take too long.
+//!
+//! A runner installs a [`Token`] on the thread that solves a puzzle, and cancels it when giving up on the answer.
+//! Long searches check [`is_cancelled`] and return early, so that the thread stops instead of running on in the background.
+
+use crate::error::PuzzleError;
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+thread_local! {
+    static TOKEN: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Shared flag to cancel solving on another thread.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+}
+impl Token {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Run `f` with this token installed on the current thread.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = TOKEN.with(|token| token.replace(Some(self.clone())));
+        let result = f();
+        TOKEN.with(|token| token.replace(previous));
+        result
+    }
+}
+
+/// Whether the token installed on the current thread has been cancelled.
+///
+/// Always false if no token is installed.
+pub fn is_cancelled() -> bool {
+    TOKEN.with(|token| token.borrow().as_ref().is_some_and(Token::is_cancelled))
+}
+
+/// Fail if the token installed on the current thread has been cancelled.
+///
+/// Used to discard the answer of a search that returned early because it was cancelled.
+pub fn check() -> Result<(), PuzzleError> {
+    if is_cancelled() { Err(PuzzleError::Cancelled) } else { Ok(()) }
+}
+
+#[test]
+fn test_scope() {
+    let token = Token::default();
+    assert!(!is_cancelled());
+    token.scope(|| {
+        assert!(!is_cancelled());
+        token.cancel();
+        assert!(is_cancelled());
+        assert_eq!(check(), Err(PuzzleError::Cancelled));
+    });
+    assert!(!is_cancelled());
+}
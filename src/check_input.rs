@@ -0,0 +1,44 @@
+//! Check that puzzle inputs parse and meet the assumptions of their puzzles, without solving them.
+
+use crate::input;
+use advent_of_code::solution::AnySolution;
+use colored::Colorize;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Parse the input of every given solution and list every problem found, or the error if an input cannot be parsed.
+///
+/// Reads from `path` if given, and from the default input file of each day otherwise.
+/// Fails if any input cannot be read or parsed, or has problems.
+pub fn check_inputs(solutions: &[&dyn AnySolution], path: Option<&Path>) -> ExitCode {
+    let mut failed = false;
+
+    for solution in solutions {
+        let day = solution.day();
+        let source = input::source_name(solution.year(), day, path);
+        let input = match input::read_input(solution.year(), day, path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{} {err}", "error:".red().bold());
+                failed = true;
+                continue;
+            }
+        };
+
+        match solution.check_input(&input) {
+            Ok(problems) if problems.is_empty() => println!("{:>10}    Day {day}", "ok".green()),
+            Ok(problems) => {
+                failed = true;
+                for problem in problems {
+                    println!("{:>10}    Day {day}: {problem}", "invalid".red().bold());
+                }
+            }
+            Err(err) => {
+                failed = true;
+                println!("{:>10}    Day {day}: {}", "error".red().bold(), err.annotate(&source).red());
+            }
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
@@ -0,0 +1,209 @@
+//! Regression corpus of extra puzzle inputs with their expected answers.
+//!
+//! Every `input/<year>/day_XX/<name>.txt` with a `<name>.answer` next to it is a case. The answer file is TOML with the
+//! expected answer of each part to check, and optionally parameters to solve with:
+//!
+//! ```toml
+//! part_1 = 26
+//! part_2 = 56000011
+//!
+//! [params]
+//! y = 10
+//! limit = 20
+//! ```
+//!
+//! An input that must be rejected has the text of its expected error instead, like `error = "waits for itself"`.
+
+use crate::answers;
+use advent_of_code::solution;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CorpusError {
+    #[error("could not read {path}: {source}")]
+    Read { path: String, source: std::io::Error },
+
+    #[error("could not parse {path}: {source}")]
+    Parse { path: String, source: toml::de::Error },
+
+    #[error("invalid entry `{key}` in {path}: {reason}")]
+    Invalid { path: String, key: String, reason: &'static str },
+
+    #[error("{path} has no input file next to it")]
+    MissingInput { path: String },
+}
+
+/// Input file with the answers expected for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub year: usize,
+    pub day: usize,
+    pub input_path: PathBuf,
+
+    /// Expected answer by part.
+    pub answers: BTreeMap<usize, String>,
+
+    /// Parameters to solve with, as `(name, value)` assignments.
+    pub params: Vec<(String, String)>,
+
+    /// Text that the error of an input that must fail to parse or validate contains.
+    pub error: Option<String>,
+}
+
+/// Find every case of a given year in the `day_XX` directories of `dir`, ordered by day and file name.
+pub fn discover(dir: &Path, year: usize) -> Result<Vec<Case>, CorpusError> {
+    let mut cases = Vec::new();
+    for day_dir in read_dir(dir)? {
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day_"))
+        else {
+            continue;
+        };
+        let Ok(day) = day.parse() else {
+            continue;
+        };
+        if !day_dir.is_dir() {
+            continue;
+        }
+
+        for answer_path in read_dir(&day_dir)? {
+            if answer_path.extension().is_some_and(|extension| extension == "answer") {
+                cases.push(load_case(year, day, &answer_path)?);
+            }
+        }
+    }
+    Ok(cases)
+}
+
+/// Sorted paths of the entries of a directory.
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, CorpusError> {
+    let read_error = |source| CorpusError::Read {
+        path: dir.display().to_string(),
+        source,
+    };
+    let mut paths = fs::read_dir(dir)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn load_case(year: usize, day: usize, answer_path: &Path) -> Result<Case, CorpusError> {
+    let path = answer_path.display().to_string();
+    let input_path = answer_path.with_extension("txt");
+    if !input_path.is_file() {
+        return Err(CorpusError::MissingInput { path });
+    }
+
+    let contents = fs::read_to_string(answer_path).map_err(|source| CorpusError::Read { path: path.clone(), source })?;
+    let table: toml::Table = contents.parse().map_err(|source| CorpusError::Parse { path: path.clone(), source })?;
+    let invalid = |key: &str, reason| CorpusError::Invalid {
+        path: path.clone(),
+        key: key.to_owned(),
+        reason,
+    };
+
+    let mut answers = BTreeMap::new();
+    let mut params = Vec::new();
+    let mut error = None;
+    for (key, value) in table {
+        if key == "params" {
+            let toml::Value::Table(table) = value else {
+                return Err(invalid(&key, "expected a table"));
+            };
+            for (name, value) in table {
+                let value = value_string(value).ok_or_else(|| invalid(&format!("params.{name}"), "expected a string or a number"))?;
+                params.push((name, value));
+            }
+            continue;
+        }
+        if key == "error" {
+            let toml::Value::String(text) = value else {
+                return Err(invalid(&key, "expected a string"));
+            };
+            error = Some(text);
+            continue;
+        }
+
+        let part = match key.as_str() {
+            "part_1" => 1,
+            "part_2" => 2,
+            _ => return Err(invalid(&key, "expected `part_1`, `part_2`, `params` or `error`")),
+        };
+        let answer = value_string(value).ok_or_else(|| invalid(&key, "expected a string or an integer"))?;
+        answers.insert(part, answer);
+    }
+
+    Ok(Case {
+        year,
+        day,
+        input_path,
+        answers,
+        params,
+        error,
+    })
+}
+
+fn value_string(value: toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s),
+        toml::Value::Integer(n) => Some(n.to_string()),
+        toml::Value::Float(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+/// Solve a case and describe every way in which it fails, prefixed with the input file name.
+pub fn check(case: &Case) -> Vec<String> {
+    let path = case.input_path.display();
+    let Some(solution) = solution::find(case.year, case.day) else {
+        return vec![format!("{path}: no solution registered for day {} of {}", case.day, case.year)];
+    };
+    let input = match fs::read_to_string(&case.input_path) {
+        Ok(input) => input,
+        Err(err) => return vec![format!("{path}: could not read input: {err}")],
+    };
+
+    let params: Vec<(&str, &str)> = case.params.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+    let parts: Vec<usize> = case.answers.keys().copied().collect();
+    let mut failures = Vec::new();
+    let parsed = solution.solve(&input, &params, &parts, &mut |part, _usage, answer| {
+        let expected = &case.answers[&part];
+        match answer {
+            Ok(answer) if answers::matches(&answer, expected) => {}
+            Ok(answer) => failures.push(format!("{path}: part {part} is {answer}, expected {expected}")),
+            Err(err) => failures.push(format!("{path}: part {part} failed: {err}")),
+        }
+    });
+    match (parsed, &case.error) {
+        (Ok(_), None) => {}
+        (Ok(_), Some(expected)) => failures.push(format!("{path}: expected to fail with `{expected}`")),
+        (Err(err), Some(expected)) if err.to_string().contains(expected.as_str()) => {}
+        (Err(err), _) => failures.push(format!("{path}: {err}")),
+    }
+    failures
+}
+
+#[test]
+fn corpus() {
+    let cases: Vec<Case> = solution::YEARS
+        .iter()
+        .map(|solutions| solutions[0].year())
+        .flat_map(|year| discover(&crate::input::year_dir(year), year).unwrap())
+        .collect();
+    let failures: Vec<String> = cases.iter().flat_map(check).collect();
+    assert!(
+        failures.is_empty(),
+        "{} failures in {} corpus cases:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,36 @@
+//! Differential testing of solutions against simple reference solutions.
+//!
+//! Days whose solutions take shortcuts have a `reference` module with brute-force solutions, which are only fast enough
+//! for small inputs. Their tests solve generated inputs both ways and compare the answers.
+//!
+//! Days that can display their parsed input also check that the text parses back to the same input.
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// Solve the input generated from each seed with both the solution and the reference, which `solve` returns in that
+/// order, and panic at the first seed for which the answers differ.
+pub fn assert_agree<T: PartialEq + Debug>(seeds: Range<u64>, mut solve: impl FnMut(&mut Rng) -> (T, T)) {
+    for seed in seeds {
+        let (answer, reference) = solve(&mut Rng::new(seed));
+        assert_eq!(answer, reference, "answer differs from the reference for seed {seed}");
+    }
+}
+
+/// Parse the input generated from each seed, display it as text with `display`, and panic at the first seed for which
+/// that text does not parse back to the same input.
+pub fn assert_round_trips<T: PartialEq + Debug>(
+    seeds: Range<u64>,
+    generate: impl Fn(&mut Rng) -> String,
+    parse: impl Fn(&'static str) -> Result<T, PuzzleError>,
+    display: impl Fn(&T) -> String,
+) {
+    for seed in seeds {
+        // Leaked, so that parsed inputs can borrow from the text.
+        let input = parse(generate(&mut Rng::new(seed)).leak()).unwrap();
+        let text = display(&input);
+        assert_eq!(parse(text.leak()).unwrap(), input, "displayed input does not parse back for seed {seed}");
+    }
+}
@@ -0,0 +1,128 @@
+//! Errors from solving puzzles.
+
+use crate::nom_complete::Expected;
+use crate::params::ParamError;
+
+/// Error from solving a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PuzzleError {
+    /// The input does not follow the format of the puzzle input.
+    #[error("parse error at line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+
+        /// The line of the input that the error is on.
+        line_text: String,
+    },
+
+    /// The input follows the format of the puzzle input, but breaks an assumption of the puzzle.
+    #[error("invalid input: {0}")]
+    Invalid(String),
+
+    /// The input is valid, but has no answer.
+    #[error("no solution: {0}")]
+    Unsolvable(String),
+
+    /// Solving was cancelled before an answer was found.
+    #[error("cancelled")]
+    Cancelled,
+
+    /// A parameter of the solution cannot be set.
+    #[error(transparent)]
+    Param(#[from] ParamError),
+}
+
+impl PuzzleError {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        PuzzleError::Invalid(message.into())
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        PuzzleError::Unsolvable(message.into())
+    }
+
+    /// Parse error at the start of `rest`, which is the part of `input` that remains to be parsed.
+    pub fn parse(input: &str, rest: &str, expected: Expected) -> Self {
+        let offset = input.len() - rest.len();
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        let line_text = input[line_start..].lines().next().unwrap_or_default().to_owned();
+
+        let found = if rest.is_empty() {
+            String::from("end of input")
+        } else if rest.starts_with(['\n', '\r']) {
+            String::from("end of line")
+        } else {
+            let rest_of_line = rest.lines().next().unwrap_or_default();
+            format!("`{}`", rest_of_line.chars().take(20).collect::<String>())
+        };
+
+        PuzzleError::Parse {
+            line,
+            column,
+            message: format!("expected {expected}, found {found}"),
+            line_text,
+        }
+    }
+
+    /// Describe the error like a compiler diagnostic, with where it is in the input named `source`, and the offending line
+    /// with a caret under where parsing failed.
+    ///
+    /// Errors without a location in the input are described as they are displayed.
+    pub fn annotate(&self, source: &str) -> String {
+        let PuzzleError::Parse {
+            line,
+            column,
+            message,
+            line_text,
+        } = self
+        else {
+            return self.to_string();
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        let caret = format!("{}^", " ".repeat(column - 1));
+        format!("{message}\n{gutter}--> {source}:{line}:{column}\n{gutter} |\n{line} | {line_text}\n{gutter} | {caret}")
+    }
+}
+
+#[test]
+fn test_parse_error_location() {
+    use crate::nom_complete::*;
+
+    let input = "1,2\n3;4\n";
+    let parse_line = terminated(separated_pair(u64, char(','), u64), line_ending);
+    let err = parse_all(input, many0(parse_line)).unwrap_err();
+    assert_eq!(
+        err,
+        PuzzleError::Parse {
+            line: 2,
+            column: 2,
+            message: String::from("expected `,`, found `;4`"),
+            line_text: String::from("3;4"),
+        }
+    );
+    assert_eq!(
+        err.annotate("input.txt"),
+        "expected `,`, found `;4`\n --> input.txt:2:2\n  |\n2 | 3;4\n  |  ^"
+    );
+}
+
+#[test]
+fn test_parse_error_in_tag() {
+    use crate::nom_complete::*;
+
+    let input = "Valve AA has flow rate=0; tunnels lead to valves DD\nValve BB has flow rate=13; pipe leads to valve CC\n";
+    let parse_line = terminated(
+        tuple((tag("Valve "), alpha1, tag(" has flow rate="), u64, tag("; tunnel"), not_line_ending)),
+        line_ending,
+    );
+    let err = parse_all(input, many1(parse_line)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 2, column 26: expected `; tunnel`, found `; pipe leads to valv`"
+    );
+}
@@ -0,0 +1,203 @@
+//! Mutation fuzzing of solutions, to find inputs that make them panic instead of failing with an error.
+//!
+//! Every run corrupts one of the examples of a day with its own seed, and solves both parts of it with the parameters
+//! of the example. Inputs that make a solution panic are written to `<dir>/<year>/day_XX/<seed>.txt`. Every input is
+//! written there before it is solved, and removed again if the run ends well, so that an input which aborts the whole
+//! process, like by overflowing the stack, is kept too.
+
+use advent_of_code::cancel;
+use advent_of_code::mutation;
+use advent_of_code::rng::Rng;
+use advent_of_code::solution::AnySolution;
+use advent_of_code::solution::Example;
+use colored::Colorize;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+
+thread_local! {
+    static PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// Where and why a solution panicked.
+#[derive(Debug)]
+struct Panic {
+    location: String,
+    message: String,
+}
+
+/// How a run ended.
+enum Outcome {
+    /// Solved, or failed with an error.
+    Done,
+
+    /// Stopped after taking too long.
+    TimedOut,
+
+    /// Took too long, and kept running in the background after being cancelled.
+    Stuck,
+    Panicked(Panic),
+}
+
+/// Fuzz every given solution for `runs` runs, with the seeds counting up from `seed`, and record every panic.
+///
+/// Without a seed, the seed is taken from the current time and shown on stderr, so that the runs can be repeated.
+/// A run that takes longer than `timeout` is cancelled and counted as timed out, which is not a failure. A day is given up
+/// on if a run does not stop when cancelled, so that such runs do not pile up in the background.
+/// Only the first input that panics at a given location is recorded. Fails if any solution panicked.
+pub fn fuzz(solutions: &[&'static dyn AnySolution], seed: Option<u64>, runs: usize, timeout: Duration, dir: &Path) -> ExitCode {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_nanos() as u64;
+        eprintln!("seed: {seed}");
+        seed
+    });
+
+    // Panics are reported with the input that caused them, instead of on stderr.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let panic = Panic {
+            location: info.location().map_or_else(|| String::from("unknown location"), ToString::to_string),
+            message: info.payload_as_str().unwrap_or("(no message)").to_owned(),
+        };
+        PANIC.with(|last| last.replace(Some(panic)));
+    }));
+
+    let mut failed = false;
+    for &solution in solutions {
+        let day = solution.day();
+        if solution.examples().is_empty() {
+            println!("{:>10}    Day {day}: no examples to mutate", "skipped".yellow());
+            continue;
+        }
+
+        let mut locations = HashSet::new();
+        let mut timed_out = 0;
+        let mut stuck = false;
+        for run in 0..runs {
+            let seed = seed.wrapping_add(run as u64);
+            let mut rng = Rng::new(seed);
+            let example = rng.choose(solution.examples());
+            let input = mutation::mutate(&mut rng, example.input);
+
+            let path = match write_input(dir, solution, seed, &input) {
+                Ok(path) => path,
+                Err(err) => {
+                    eprintln!("{} could not record input of seed {seed}: {err}", "error:".red().bold());
+                    panic::set_hook(default_hook);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let keep = match run_once(solution, &input, example.params, timeout) {
+                Outcome::Done => false,
+                Outcome::TimedOut => {
+                    timed_out += 1;
+                    false
+                }
+                Outcome::Stuck => {
+                    println!(
+                        "{:>10}    Day {day}: did not stop when cancelled, skipping the remaining runs",
+                        "stuck".yellow().bold()
+                    );
+                    stuck = true;
+                    true
+                }
+                Outcome::Panicked(panic) => {
+                    failed = true;
+                    let first = locations.insert(panic.location.clone());
+                    if first {
+                        println!(
+                            "{:>10}    Day {day}: panicked at {}: {}",
+                            "panicked".red().bold(),
+                            panic.location,
+                            panic.message
+                        );
+                    }
+                    first
+                }
+            };
+            if keep {
+                show_reproduce(solution, example, &path);
+            } else if let Err(err) = fs::remove_file(&path) {
+                eprintln!("{} could not remove input of seed {seed}: {err}", "error:".red().bold());
+            }
+            if stuck {
+                break;
+            }
+        }
+
+        if locations.is_empty() && !stuck {
+            println!("{:>10}    Day {day}: {runs} runs, {timed_out} timed out", "ok".green());
+        }
+    }
+
+    panic::set_hook(default_hook);
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Solve both parts of the input on another thread, and give up after `timeout`.
+fn run_once(solution: &'static dyn AnySolution, input: &str, params: &'static [(&'static str, &'static str)], timeout: Duration) -> Outcome {
+    let token = cancel::Token::default();
+    let (sender, receiver) = mpsc::channel();
+    {
+        let token = token.clone();
+        let input = input.to_owned();
+        thread::spawn(move || {
+            let solved = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+                // Errors are fine, only panics are not.
+                token.scope(|| solution.solve(&input, params, &[1, 2], &mut |_, _, _| {}))
+            }));
+            let outcome = match solved {
+                Ok(_) => Outcome::Done,
+                Err(_) => Outcome::Panicked(PANIC.with(|last| last.take()).expect("the panic hook records every panic")),
+            };
+            // The receiver is gone if it gave up, which is fine.
+            let _ = sender.send(outcome);
+        });
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(outcome) => outcome,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            // Give the run as long again to stop.
+            match receiver.recv_timeout(timeout) {
+                Ok(Outcome::Panicked(panic)) => Outcome::Panicked(panic),
+                Ok(_) => Outcome::TimedOut,
+                Err(RecvTimeoutError::Timeout) => Outcome::Stuck,
+                Err(RecvTimeoutError::Disconnected) => unreachable!("panics are caught, so an outcome is always sent"),
+            }
+        }
+        Err(RecvTimeoutError::Disconnected) => unreachable!("panics are caught, so an outcome is always sent"),
+    }
+}
+
+/// Show how to solve the recorded input of a run again.
+fn show_reproduce(solution: &dyn AnySolution, example: &Example, path: &Path) {
+    let params: String = example.params.iter().map(|(name, value)| format!(" --param {name}={value}")).collect();
+    println!(
+        "{:>10}    reproduce with `cargo run -- --year {} {} --input {}{params}`",
+        "",
+        solution.year(),
+        solution.day(),
+        path.display()
+    );
+}
+
+/// Write the input of a run to its file in `dir`.
+fn write_input(dir: &Path, solution: &dyn AnySolution, seed: u64, input: &str) -> io::Result<PathBuf> {
+    let day_dir = dir.join(solution.year().to_string()).join(format!("day_{:02}", solution.day()));
+    fs::create_dir_all(&day_dir)?;
+    let path = day_dir.join(format!("{seed}.txt"));
+    fs::write(&path, input)?;
+    Ok(path)
+}
@@ -0,0 +1,35 @@
+//! Random puzzle inputs, to try solutions on more than the real input.
+
+use advent_of_code::rng::Rng;
+use advent_of_code::solution::AnySolution;
+use colored::Colorize;
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+/// Generate a random input of the given size for a solution, and write it to `path`, or to stdout if not given.
+///
+/// Without a seed, the seed is taken from the current time and shown on stderr, so that the input can be generated again.
+pub fn generate(solution: &dyn AnySolution, seed: Option<u64>, size: usize, path: Option<&Path>) -> ExitCode {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_nanos() as u64;
+        eprintln!("seed: {seed}");
+        seed
+    });
+    let input = solution.generate(&mut Rng::new(seed), size);
+
+    let written = match path {
+        Some(path) => fs::write(path, input),
+        None => io::stdout().write_all(input.as_bytes()),
+    };
+    match written {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{} could not write input: {err}", "error:".red().bold());
+            ExitCode::FAILURE
+        }
+    }
+}
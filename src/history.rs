@@ -0,0 +1,310 @@
+//! Local history of timings, to follow the performance of solutions across commits.
+//!
+//! Every run on the puzzle inputs appends the timings of the solved parts to a CSV file, keyed by the git commit, the
+//! build profile and the time of the run.
+
+use crate::report::Record;
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::process::ExitCode;
+use std::time::Duration;
+use std::time::SystemTime;
+
+/// Default location of the history file.
+pub const DEFAULT_PATH: &str = "perf-history.csv";
+
+const HEADER: &str = "commit,profile,timestamp,year,day,part,parse_ns,solve_ns";
+
+/// Number of most recent commits shown in the trend of a part.
+const TREND_COMMITS: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+pub enum HistoryError {
+    #[error("could not read history file {path}: {source}")]
+    Read { path: String, source: io::Error },
+
+    #[error("invalid line {line} in history file {path}")]
+    Invalid { path: String, line: usize },
+}
+
+/// Timing of one part in one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Short hash of the commit that was run, with `-dirty` appended if there were uncommitted changes.
+    pub commit: String,
+
+    /// Build profile that was run, such as `release` or `debug+count-allocations`.
+    pub profile: String,
+
+    /// Start of the run, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+}
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.commit, self.profile, self.timestamp, self.year, self.day, self.part, self.parse_ns, self.solve_ns
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split(',');
+        let entry = Entry {
+            commit: fields.next()?.to_owned(),
+            profile: fields.next()?.to_owned(),
+            timestamp: fields.next()?.parse().ok()?,
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            parse_ns: fields.next()?.parse().ok()?,
+            solve_ns: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(entry)
+    }
+}
+
+/// Append the timings of the given records to the history file, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let commit = current_commit();
+    let profile = current_profile();
+    let timestamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs();
+
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut lines = String::new();
+    if is_new {
+        lines.push_str(HEADER);
+        lines.push('\n');
+    }
+    for record in records {
+        let entry = Entry {
+            commit: commit.clone(),
+            profile: profile.clone(),
+            timestamp,
+            year: record.year,
+            day: record.day,
+            part: record.part,
+            parse_ns: record.parse_elapsed.as_nanos(),
+            solve_ns: record.solve_elapsed.as_nanos(),
+        };
+        lines.push_str(&entry.to_line());
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes())
+}
+
+/// Short hash of the checked out commit, or `unknown` outside of a git repository.
+fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) if git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|status| !status.is_empty()) => {
+            format!("{commit}-dirty")
+        }
+        Some(commit) => commit,
+        None => String::from("unknown"),
+    }
+}
+
+/// Build profile of this binary, with the enabled features that change timings.
+fn current_profile() -> String {
+    let mut profile = String::from(if cfg!(debug_assertions) { "debug" } else { "release" });
+    if cfg!(feature = "count-allocations") {
+        profile.push_str("+count-allocations");
+    }
+    profile
+}
+
+/// Entries of the history file, in the order they were appended.
+pub fn load(path: &Path) -> Result<Vec<Entry>, HistoryError> {
+    let contents = fs::read_to_string(path).map_err(|source| HistoryError::Read {
+        path: path.display().to_string(),
+        source,
+    })?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|&(idx, line)| !(idx == 0 && line == HEADER) && !line.is_empty())
+        .map(|(idx, line)| {
+            Entry::from_line(line).ok_or_else(|| HistoryError::Invalid {
+                path: path.display().to_string(),
+                line: idx + 1,
+            })
+        })
+        .collect()
+}
+
+/// Median solve time of one part at one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CommitMedian {
+    commit: String,
+    median: Duration,
+}
+
+/// Solve times in nanoseconds per commit, with commits in the order they were first run.
+type CommitSamples = Vec<(String, Vec<u128>)>;
+
+/// Year, day, part and build profile, as timings of different profiles are not comparable.
+type PartProfile = (usize, usize, usize, String);
+
+/// Median solve times of every part per commit and profile, with commits in the order they were first run.
+fn medians(entries: &[Entry]) -> BTreeMap<PartProfile, Vec<CommitMedian>> {
+    let mut samples: BTreeMap<PartProfile, CommitSamples> = BTreeMap::new();
+    for entry in entries {
+        let commits = samples.entry((entry.year, entry.day, entry.part, entry.profile.clone())).or_default();
+        match commits.iter_mut().find(|(commit, _)| *commit == entry.commit) {
+            Some((_, solve_ns)) => solve_ns.push(entry.solve_ns),
+            None => commits.push((entry.commit.clone(), vec![entry.solve_ns])),
+        }
+    }
+
+    samples
+        .into_iter()
+        .map(|(key, commits)| {
+            let medians = commits
+                .into_iter()
+                .map(|(commit, mut solve_ns)| {
+                    solve_ns.sort_unstable();
+                    let median = solve_ns[solve_ns.len() / 2];
+                    CommitMedian {
+                        commit,
+                        median: Duration::from_nanos(median as u64),
+                    }
+                })
+                .collect();
+            (key, medians)
+        })
+        .collect()
+}
+
+/// Relative change from `previous` to `latest`, in percent.
+fn change_percent(previous: Duration, latest: Duration) -> f64 {
+    (latest.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Sparkline of durations, scaled from the shortest to the longest.
+fn sparkline(durations: &[Duration]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = durations.iter().min().copied().unwrap_or_default().as_secs_f64();
+    let max = durations.iter().max().copied().unwrap_or_default().as_secs_f64();
+    durations
+        .iter()
+        .map(|duration| {
+            let scaled = if max > min { (duration.as_secs_f64() - min) / (max - min) } else { 0.0 };
+            BARS[(scaled * (BARS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
+/// Show the trend of the median solve time of every part and profile over the last commits in the history file, and flag
+/// parts whose median at the latest commit is more than `threshold` percent slower than at the commit before.
+///
+/// Fails if any part regressed.
+pub fn perf_report(path: &Path, threshold: f64) -> ExitCode {
+    let entries = match load(path) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!("{} {err}", "error:".red().bold());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let medians = medians(&entries);
+    let profile_width = medians.keys().map(|(_, _, _, profile)| profile.len()).max().unwrap_or_default();
+    let mut regressed = false;
+    for ((year, day, part, profile), medians) in medians {
+        let recent = &medians[medians.len().saturating_sub(TREND_COMMITS)..];
+        let durations: Vec<Duration> = recent.iter().map(|m| m.median).collect();
+        let latest = recent.last().expect("every part has at least one entry");
+
+        let change = match recent {
+            [.., previous, _] => {
+                let change = change_percent(previous.median, latest.median);
+                let text = format!("{change:+.1}% since {}", previous.commit);
+                if change > threshold {
+                    regressed = true;
+                    format!("{}  {}", text.red(), "REGRESSED".red().bold())
+                } else if change < -threshold {
+                    text.green().to_string()
+                } else {
+                    text
+                }
+            }
+            _ => String::new(),
+        };
+        let line = format!(
+            "{year} Day {day:>2} Part {part}  {profile:<profile_width$}  {:<width$}  {:>10} at {:<14}  {change}",
+            sparkline(&durations),
+            format!("{:.2?}", latest.median),
+            latest.commit,
+            width = TREND_COMMITS
+        );
+        println!("{}", line.trim_end());
+    }
+
+    if regressed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+#[test]
+fn test_entry_line() {
+    let entry = Entry {
+        commit: String::from("8c064a5-dirty"),
+        profile: String::from("release+count-allocations"),
+        timestamp: 1_700_000_000,
+        year: 2022,
+        day: 16,
+        part: 2,
+        parse_ns: 52_000,
+        solve_ns: 155_000_000_000,
+    };
+    assert_eq!(Entry::from_line(&entry.to_line()), Some(entry));
+    assert_eq!(Entry::from_line("8c064a5,release,1700000000,2022,16,2,52000"), None);
+}
+
+#[test]
+fn test_medians() {
+    let entry = |commit: &str, profile: &str, solve_ns| Entry {
+        commit: commit.to_owned(),
+        profile: profile.to_owned(),
+        timestamp: 0,
+        year: 2022,
+        day: 1,
+        part: 1,
+        parse_ns: 0,
+        solve_ns,
+    };
+    let entries = [
+        entry("b", "release", 30),
+        entry("a", "release", 10),
+        entry("b", "release", 20),
+        entry("b", "release", 90),
+        entry("a", "debug", 900),
+    ];
+    let medians = medians(&entries);
+    let release = &medians[&(2022, 1, 1, String::from("release"))];
+    assert_eq!(release[0].commit, "b");
+    assert_eq!(release[0].median, Duration::from_nanos(30));
+    assert_eq!(release[1].median, Duration::from_nanos(10));
+    assert_eq!(change_percent(release[1].median, release[0].median).round(), 200.0);
+    let debug = &medians[&(2022, 1, 1, String::from("debug"))];
+    assert_eq!(debug.len(), 1);
+    assert_eq!(debug[0].median, Duration::from_nanos(900));
+    assert_eq!(sparkline(&[Duration::from_nanos(10), Duration::from_nanos(30)]), "▁█");
+}
@@ -0,0 +1,54 @@
+use advent_of_code::normalize::normalize;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Path that stands for stdin when given as input file.
+const STDIN: &str = "-";
+
+/// Directory with the puzzle inputs of a given year.
+pub fn year_dir(year: usize) -> PathBuf {
+    Path::new("input").join(year.to_string())
+}
+
+/// Path of the puzzle input for a given year and day, when no other input file is given.
+pub fn default_path(year: usize, day: usize) -> PathBuf {
+    year_dir(year).join(format!("day_{day:02}.txt"))
+}
+
+/// Name of where the input for a given year and day is read from, to show in messages.
+pub fn source_name(year: usize, day: usize, path: Option<&Path>) -> String {
+    match path {
+        Some(path) if is_stdin(path) => String::from("<stdin>"),
+        Some(path) => path.display().to_string(),
+        None => default_path(year, day).display().to_string(),
+    }
+}
+
+/// Whether a path given as input file stands for stdin.
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+/// Read puzzle input for a given year and day, [normalized](normalize) for parsing.
+///
+/// Reads from `path` if given, where `-` means stdin, and from the default input file of the day otherwise.
+pub fn read_input(year: usize, day: usize, path: Option<&Path>) -> io::Result<String> {
+    let input = match path {
+        Some(path) if is_stdin(path) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| io::Error::new(err.kind(), format!("could not read input from stdin: {err}")))?;
+            input
+        }
+        Some(path) => read_file(path)?,
+        None => read_file(&default_path(year, day))?,
+    };
+    Ok(normalize(&input).into_owned())
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path).map_err(|err| io::Error::new(err.kind(), format!("could not read input file {}: {err}", path.display())))
+}
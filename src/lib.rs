@@ -0,0 +1,28 @@
+//! Solutions to the puzzles of Advent of Code, and the utilities they are built on.
+//!
+//! The solutions of each year are in a `year_YYYY` module. Each day module exposes `part_1` and `part_2` over the parsed
+//! puzzle input, and a `parser` module that parses it.
+
+#![feature(binary_heap_into_iter_sorted)]
+#![feature(int_roundings)]
+
+pub mod alloc_stats;
+pub mod cancel;
+#[cfg(test)]
+pub mod differential;
+pub mod error;
+pub mod mutation;
+pub mod nom_complete;
+pub mod normalize;
+pub mod params;
+pub mod rng;
+pub mod solution;
+
+solution::years! {
+    year_2022,
+}
+
+pub mod lib {
+    pub mod graph;
+    pub mod vector_2d;
+}
@@ -1,175 +1,566 @@
-#![feature(binary_heap_into_iter_sorted)]
-#![feature(array_chunks)]
-#![feature(int_roundings)]
-
+use advent_of_code::cancel;
+use advent_of_code::error::PuzzleError;
+use advent_of_code::solution;
+use advent_of_code::solution::AnySolution;
+use advent_of_code::solution::Usage;
+use answers::Answers;
+use clap::CommandFactory;
 use clap::Parser;
+use clap::Subcommand;
+use clap::builder::RangedU64ValueParser;
+use clap::error::ErrorKind;
 use colored::Colorize;
+use report::Format;
+use report::Record;
+use report::Reporter;
+use report::Status;
+use std::io;
+use std::num::NonZeroUsize;
 use std::path::Path;
-use std::time::Instant;
-
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-mod day_21;
-
-mod nom_complete {
-    pub use nom::branch::*;
-    pub use nom::bytes::complete::*;
-    pub use nom::character::complete::*;
-    pub use nom::character::*;
-    pub use nom::combinator::*;
-    pub use nom::multi::*;
-    pub use nom::sequence::*;
-    pub use nom::Finish;
-    pub use nom::IResult;
-}
-
-mod lib {
-    pub mod graph;
-    pub mod vector_2d;
-}
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::OnceLock;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+/// Count the allocations of solutions, to report them next to the timings.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code::alloc_stats::Counting = advent_of_code::alloc_stats::Counting;
+
+mod answers;
+mod bench;
+mod check_input;
+mod corpus;
+mod fuzz;
+mod generate;
+mod history;
+mod input;
+mod pool;
+mod report;
+mod scaffold;
+mod verify;
+mod watch;
 
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     day: Option<usize>,
+
+    #[arg(value_parser = part_parser())]
     part: Option<usize>,
+
+    /// Year of the puzzles, by default the latest year with solutions.
+    #[arg(long, global = true, value_parser = RangedU64ValueParser::<usize>::new().range(2015..))]
+    year: Option<usize>,
+
+    /// Read puzzle input from this file instead of `input/<year>/day_XX.txt`, or from stdin if `-`.
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Solve the examples from the puzzle description instead of the puzzle input, or only the named example.
+    #[arg(long, requires = "day", conflicts_with = "input", value_name = "NAME")]
+    example: Option<Option<String>>,
+
+    /// Set a parameter of the puzzle, such as a size that differs between the examples and the real input.
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param, requires = "day")]
+    params: Vec<(String, String)>,
+
+    /// Give up on a part that takes longer than this to solve, e.g. `500ms`, `30s` or `2m`, and continue with the rest.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Do not append the timings to the performance history.
+    #[arg(long)]
+    no_history: bool,
+
+    /// Solve again every time the input file changes, until interrupted.
+    #[arg(long, requires = "day", conflicts_with = "example")]
+    watch: bool,
+
+    /// Output format of the results.
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Number of parts to solve in parallel.
+    #[arg(long, short, default_value = "1")]
+    jobs: NonZeroUsize,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve every day (or only the given day) and check the answers against the known answers.
+    Verify {
+        day: Option<usize>,
+
+        /// File with known answers, by default `input/<year>/answers.toml`.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// Also solve the regression corpus of extra inputs in `input/<year>/day_XX/`, and check their expected answers.
+        #[arg(long)]
+        corpus: bool,
+    },
+
+    /// Parse the input of every day (or only the given day) and list every problem that breaks an assumption of the puzzle,
+    /// without solving it.
+    CheckInput {
+        day: Option<usize>,
+
+        /// Read puzzle input from this file instead of `input/<year>/day_XX.txt`, or from stdin if `-`.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+
+    /// Write a random puzzle input for the given day, which parses and meets the assumptions of the puzzle.
+    ///
+    /// The same seed and size always give the same input, so that an input that a solution fails on can be reproduced.
+    Generate {
+        day: usize,
+
+        /// Seed of the random numbers, by default taken from the current time and shown on stderr.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Size of the input, such as a number of lines or monkeys, depending on the puzzle.
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Write the input to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Solve corrupted copies of the examples of every day (or only the given day), and record every input that makes a
+    /// solution panic.
+    ///
+    /// Every run mutates an example with its own seed, so that a run can be repeated with `--seed <SEED> --runs 1`.
+    Fuzz {
+        day: Option<usize>,
+
+        /// Seed of the first run, by default taken from the current time and shown on stderr.
+        #[arg(long)]
+        seed: Option<u64>,
+
+        /// Number of runs per day.
+        #[arg(long, default_value_t = 200)]
+        runs: usize,
+
+        /// Give up on a run that takes longer than this, e.g. `500ms` or `2s`.
+        #[arg(long, value_name = "DURATION", value_parser = parse_duration, default_value = "1s")]
+        timeout: Duration,
+
+        /// Directory to write the inputs that make a solution panic to, as `<year>/day_XX/<seed>.txt`.
+        #[arg(long, default_value = "fuzz")]
+        dir: PathBuf,
+    },
+
+    /// Time solutions over repeated runs and report min, median, mean and standard deviation.
+    Bench {
+        day: Option<usize>,
+
+        #[arg(value_parser = part_parser())]
+        part: Option<usize>,
+
+        /// Number of untimed runs before timing.
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Number of timed runs.
+        #[arg(long, default_value = "10")]
+        runs: NonZeroUsize,
+    },
+
+    /// Show the trend of the median solve time of every part in the performance history, and flag regressions.
+    PerfReport {
+        /// Flag parts whose median at the latest commit is more than this many percent slower than at the commit before.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// File with the performance history.
+        #[arg(long, default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+    },
+
+    /// Create the module of a new day from the template, register it, and create an empty input file.
+    ///
+    /// The day is added to the latest year with solutions, unless another year is given.
+    NewDay {
+        #[arg(value_parser = RangedU64ValueParser::<usize>::new().range(1..=25))]
+        day: usize,
+
+        /// Title of the puzzle.
+        #[arg(long)]
+        title: String,
+    },
+}
+
+fn part_parser() -> RangedU64ValueParser<usize> {
+    RangedU64ValueParser::new().range(1..=2)
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (number, unit) = s.split_at(s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len()));
+    let seconds_per_unit = match unit {
+        "ms" => 0.001,
+        "s" | "" => 1.0,
+        "m" => 60.0,
+        _ => return Err(format!("unknown unit `{unit}`, expected `ms`, `s` or `m`")),
+    };
+    let number: f64 = number.parse().map_err(|err| format!("invalid number `{number}`: {err}"))?;
+    Duration::try_from_secs_f64(number * seconds_per_unit).map_err(|err| err.to_string())
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) => Ok((name.to_owned(), value.to_owned())),
+        None => Err(format!("expected NAME=VALUE, found `{s}`")),
+    }
 }
 
-fn main() {
+fn main() -> ExitCode {
     env_logger::builder().parse_default_env().init();
     let cli = Cli::parse();
 
-    if let Some(day) = cli.day {
-        if let Some(part) = cli.part {
-            solve(day, part);
-        } else {
-            solve(day, 1);
-            solve(day, 2);
+    match cli.command {
+        Some(Command::Verify { day, answers, corpus }) => {
+            let year = select_year(cli.year);
+            let answers = match Answers::load(&answers.unwrap_or_else(|| answers::default_path(year))) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("{} {err}", "error:".red().bold());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let exit_code = verify::verify(&select_solutions(year, day), &answers);
+            if corpus && verify::verify_corpus(&input::year_dir(year), year, day) == ExitCode::FAILURE {
+                return ExitCode::FAILURE;
+            }
+            exit_code
+        }
+        Some(Command::CheckInput { day, input }) => check_input::check_inputs(&select_solutions(select_year(cli.year), day), input.as_deref()),
+        Some(Command::Generate { day, seed, size, output }) => {
+            let solution = select_solutions(select_year(cli.year), Some(day))[0];
+            generate::generate(solution, seed, size, output.as_deref())
+        }
+        Some(Command::Fuzz {
+            day,
+            seed,
+            runs,
+            timeout,
+            dir,
+        }) => fuzz::fuzz(&select_solutions(select_year(cli.year), day), seed, runs, timeout, &dir),
+        Some(Command::Bench { day, part, warmup, runs }) => {
+            bench::bench(&select_solutions(select_year(cli.year), day), &select_parts(part), warmup, runs)
         }
-    } else {
-        solve(1, 1);
-        solve(1, 2);
-        solve(2, 1);
-        solve(2, 2);
-        solve(3, 1);
-        solve(3, 2);
-        solve(4, 1);
-        solve(4, 2);
-        solve(5, 1);
-        solve(5, 2);
-        solve(6, 1);
-        solve(6, 2);
-        solve(7, 1);
-        solve(7, 2);
-        solve(8, 1);
-        solve(8, 2);
-        solve(9, 1);
-        solve(9, 2);
-        solve(10, 1);
-        solve(10, 2);
-        solve(11, 1);
-        solve(11, 2);
-        solve(12, 1);
-        solve(12, 2);
-        solve(13, 1);
-        solve(13, 2);
-        solve(14, 1);
-        solve(14, 2);
-        solve(15, 1);
-        solve(15, 2);
-        solve(16, 1);
-        solve(16, 2);
-        solve(17, 1);
-        solve(17, 2);
-        solve(18, 1);
-        solve(18, 2);
-        solve(19, 1);
-        solve(19, 2);
-        solve(20, 1);
-        solve(20, 2);
-        solve(21, 1);
-        solve(21, 2);
+        Some(Command::PerfReport { threshold, history }) => history::perf_report(&history, threshold),
+        Some(Command::NewDay { day, title }) => match scaffold::new_day(Path::new(""), cli.year.unwrap_or_else(solution::latest_year), day, &title) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{} {err}", "error:".red().bold());
+                ExitCode::FAILURE
+            }
+        },
+        None => match &cli.example {
+            Some(name) => run_examples(&cli, name.as_deref()),
+            None if cli.watch => watch(&cli),
+            None => run(&cli),
+        },
     }
 }
 
-fn solve(day: usize, part: usize) {
-    let input = read_input(format!("day_{day:02}.txt"));
-
-    let now = Instant::now();
-    let solution = match (day, part) {
-        (1, 1) => day_01::part_1(&input).to_string(),
-        (1, 2) => day_01::part_2(&input).to_string(),
-        (2, 1) => day_02::part_1(&input).to_string(),
-        (2, 2) => day_02::part_2(&input).to_string(),
-        (3, 1) => day_03::part_1(&input).to_string(),
-        (3, 2) => day_03::part_2(&input).to_string(),
-        (4, 1) => day_04::part_1(&input).to_string(),
-        (4, 2) => day_04::part_2(&input).to_string(),
-        (5, 1) => day_05::part_1(&input),
-        (5, 2) => day_05::part_2(&input),
-        (6, 1) => day_06::part_1(&input).to_string(),
-        (6, 2) => day_06::part_2(&input).to_string(),
-        (7, 1) => day_07::part_1(&input).to_string(),
-        (7, 2) => day_07::part_2(&input).to_string(),
-        (8, 1) => day_08::part_1(&input).to_string(),
-        (8, 2) => day_08::part_2(&input).to_string(),
-        (9, 1) => day_09::part_1(&input).to_string(),
-        (9, 2) => day_09::part_2(&input).to_string(),
-        (10, 1) => day_10::part_1(&input).to_string(),
-        (10, 2) => day_10::part_2(&input).to_string(),
-        (11, 1) => day_11::part_1(&input).to_string(),
-        (11, 2) => day_11::part_2(&input).to_string(),
-        (12, 1) => day_12::part_1(&input).to_string(),
-        (12, 2) => day_12::part_2(&input).to_string(),
-        (13, 1) => day_13::part_1(&input).to_string(),
-        (13, 2) => day_13::part_2(&input).to_string(),
-        (14, 1) => day_14::part_1(&input).to_string(),
-        (14, 2) => day_14::part_2(&input).to_string(),
-        (15, 1) => day_15::part_1(&input).to_string(),
-        (15, 2) => day_15::part_2(&input).to_string(),
-        (16, 1) => day_16::part_1(&input).to_string(),
-        (16, 2) => day_16::part_2(&input).to_string(),
-        (17, 1) => day_17::part_1(&input).to_string(),
-        (17, 2) => day_17::part_2(&input).to_string(),
-        (18, 1) => day_18::part_1(&input).to_string(),
-        (18, 2) => day_18::part_2(&input).to_string(),
-        (19, 1) => day_19::part_1(&input).to_string(),
-        (19, 2) => day_19::part_2(&input).to_string(),
-        (20, 1) => day_20::part_1(&input).to_string(),
-        (20, 2) => day_20::part_2(&input).to_string(),
-        (21, 1) => day_21::part_1(&input).to_string(),
-        (21, 2) => day_21::part_2(&input).to_string(),
-        _ => panic!(),
+/// The given year, or the latest year if no year is given.
+///
+/// Exits with a usage error if there are no solutions for the given year.
+fn select_year(year: Option<usize>) -> usize {
+    match year {
+        Some(year) if solution::solutions(year).is_none() => {
+            let years: Vec<String> = solution::YEARS.iter().map(|solutions| solutions[0].year().to_string()).collect();
+            Cli::command()
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("no solutions registered for {year}, expected one of: {}", years.join(", ")),
+                )
+                .exit()
+        }
+        Some(year) => year,
+        None => solution::latest_year(),
+    }
+}
+
+/// Solutions of a year for the given day, or all solutions of the year if no day is given.
+///
+/// Exits with a usage error if there is no solution for the given day.
+fn select_solutions(year: usize, day: Option<usize>) -> Vec<&'static dyn AnySolution> {
+    match day {
+        Some(day) => match solution::find(year, day) {
+            Some(solution) => vec![solution],
+            None => Cli::command()
+                .error(ErrorKind::InvalidValue, format!("no solution registered for day {day} of {year}"))
+                .exit(),
+        },
+        None => solution::solutions(year).expect("year is registered").to_vec(),
+    }
+}
+
+/// The given part, or both parts if no part is given.
+fn select_parts(part: Option<usize>) -> Vec<usize> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// The parameters given on the command line, as `(name, value)` assignments.
+///
+/// Exits with a usage error if a solution has no parameter with one of the given names.
+fn select_params<'c>(cli: &'c Cli, solutions: &[&dyn AnySolution]) -> Vec<(&'c str, &'c str)> {
+    for solution in solutions {
+        let names = solution.param_names();
+        if let Some((name, _)) = cli.params.iter().find(|(name, _)| !names.contains(&name.as_str())) {
+            let expected = if names.is_empty() { "(none)".to_owned() } else { names.join(", ") };
+            Cli::command()
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("no parameter named `{name}` for day {}, expected one of: {expected}", solution.day()),
+                )
+                .exit()
+        }
+    }
+    cli.params.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect()
+}
+
+fn run(cli: &Cli) -> ExitCode {
+    let year = select_year(cli.year);
+    let parts = select_parts(cli.part);
+    let solutions = select_solutions(year, cli.day);
+    let params = select_params(cli, &solutions);
+    let days: Vec<Day> = solutions
+        .into_iter()
+        .map(|solution| Day {
+            solution,
+            source: input::source_name(year, solution.day(), cli.input.as_deref()),
+            input: input::read_input(year, solution.day(), cli.input.as_deref()),
+            requests: OnceLock::new(),
+        })
+        .collect();
+    let tasks: Vec<(&Day, usize)> = days.iter().flat_map(|day| parts.iter().map(move |&part| (day, part))).collect();
+
+    let mut reporter = Reporter::new(cli.format);
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut solved = Vec::new();
+    pool::map_ordered(
+        &tasks,
+        cli.jobs,
+        |&(day, part)| match &day.input {
+            // The input is parsed for the first part that is solved.
+            Ok(input) => {
+                let requests = day.requests.get_or_init(|| spawn_day(day.solution, &params, input.clone()));
+                solve(day, requests, part, part == parts[0], cli.timeout)
+            }
+            Err(err) => failed(day.solution, part, Status::Error(err.to_string())),
+        },
+        |record| {
+            reporter.report(&record);
+            if record.status == Status::Ok {
+                solved.push(record);
+            } else {
+                exit_code = ExitCode::FAILURE;
+            }
+        },
+    );
+
+    // Timings on other inputs or parameters are not comparable with the history, nor are those of code that is being
+    // edited in watch mode.
+    if !cli.no_history && !cli.watch && cli.input.is_none() && cli.params.is_empty() && !solved.is_empty() {
+        if let Err(err) = history::append(Path::new(history::DEFAULT_PATH), &solved) {
+            eprintln!(
+                "{} could not append to performance history {}: {err}",
+                "warning:".yellow().bold(),
+                history::DEFAULT_PATH
+            );
+        }
+    }
+
+    exit_code
+}
+
+/// Solve the given day every time its input file changes.
+///
+/// Exits with a usage error if the input is read from stdin, which cannot be watched.
+fn watch(cli: &Cli) -> ! {
+    let path = match (&cli.input, cli.day) {
+        (Some(path), _) if input::is_stdin(path) => Cli::command().error(ErrorKind::ArgumentConflict, "cannot watch stdin for changes").exit(),
+        (Some(path), _) => path.clone(),
+        (None, Some(day)) => input::default_path(select_year(cli.year), day),
+        (None, None) => unreachable!("--watch requires a day"),
     };
-    let elapsed = now.elapsed();
+    watch::watch(&path, || {
+        run(cli);
+    })
+}
 
-    let micros = elapsed.as_micros();
-    let time = match micros {
-        x if x < 10_000 => format!("{}μs", micros).green(),
-        x if x < 10_000_000 => format!("{}ms", micros / 1000).yellow(),
-        _ => format!("{}s", micros / 1000 / 1000).red(),
+/// Solve the examples of the given day, or only the named example, and compare with their expected answers.
+///
+/// Exits with a usage error if the day has no example with the given name.
+fn run_examples(cli: &Cli, name: Option<&str>) -> ExitCode {
+    let solution = select_solutions(select_year(cli.year), cli.day)[0];
+    let params = select_params(cli, &[solution]);
+    let examples = solution.examples();
+    let examples = match name {
+        Some(name) => match examples.iter().find(|example| example.name == name) {
+            Some(example) => std::slice::from_ref(example),
+            None => {
+                let names: Vec<&str> = examples.iter().map(|example| example.name).collect();
+                Cli::command()
+                    .error(
+                        ErrorKind::InvalidValue,
+                        format!(
+                            "no example named `{name}` for day {}, expected one of: {}",
+                            solution.day(),
+                            names.join(", ")
+                        ),
+                    )
+                    .exit()
+            }
+        },
+        None => examples,
     };
+    verify::verify_examples(solution, examples, &params, &select_parts(cli.part))
+}
+
+/// Solution of a day with its input, which is read from `source`.
+struct Day {
+    solution: &'static dyn AnySolution,
+    source: String,
+    input: io::Result<String>,
 
-    println!("{time:>10}    Day {day} Part {part}: {solution}");
+    /// Where to request parts from the thread of the day, once it is started.
+    requests: OnceLock<mpsc::Sender<PartRequest>>,
+}
+
+/// Part to solve, with the token to cancel it by, and where to send it once solved.
+type PartRequest = (usize, cancel::Token, mpsc::Sender<Solved>);
+
+/// Time it took to parse the input, and what it took to solve a part along with the answer, or why the input could not
+/// be parsed.
+type Solved = Result<(Duration, Usage, Result<String, PuzzleError>), PuzzleError>;
+
+/// Start a thread that parses the input of a day once, and then solves every part that is requested on a thread of its
+/// own, so that the parts share the parsed input.
+///
+/// The thread stops once the returned sender is dropped and the parts that it started are done. Parts that are cancelled
+/// but keep running are left behind, along with the thread.
+fn spawn_day(solution: &'static dyn AnySolution, params: &[(&str, &str)], input: String) -> mpsc::Sender<PartRequest> {
+    let (sender, requests) = mpsc::channel::<PartRequest>();
+    let params: Vec<(String, String)> = params.iter().map(|&(name, value)| (name.to_owned(), value.to_owned())).collect();
+    thread::spawn(move || {
+        let params: Vec<(&str, &str)> = params.iter().map(|(name, value)| (name.as_str(), value.as_str())).collect();
+        solution.with_parsed(&input, &params, &mut |parsed| match parsed {
+            Ok((parse_elapsed, solve_part)) => thread::scope(|scope| {
+                for (part, token, solved) in &requests {
+                    scope.spawn(move || {
+                        let (usage, answer) = token.scope(|| solve_part(part));
+                        // The receiver is gone if it gave up, which is fine.
+                        let _ = solved.send(Ok((parse_elapsed, usage, answer)));
+                    });
+                }
+            }),
+            Err(err) => {
+                for (_, _, solved) in &requests {
+                    let _ = solved.send(Err(err.clone()));
+                }
+            }
+        });
+    });
+    sender
+}
+
+/// Solve a part of a day on the thread of the day, and give up if it takes longer than `timeout`.
+///
+/// The timeout includes waiting for the input to be parsed. A part that takes too long is cancelled, and reported as
+/// timed out. Where the input stops making sense to the parser is only shown for the `first` part of the day.
+fn solve(day: &Day, requests: &mpsc::Sender<PartRequest>, part: usize, first: bool, timeout: Option<Duration>) -> Record {
+    let solution = day.solution;
+    log::debug!("Day {} part {part}: {}", solution.day(), solution.title());
+
+    let token = cancel::Token::default();
+    let (sender, receiver) = mpsc::channel();
+    // The thread of the day is only gone if it panicked.
+    if requests.send((part, token.clone(), sender)).is_err() {
+        return failed(solution, part, Status::Error(String::from("solution panicked")));
+    }
+
+    let solved = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match solved {
+        Ok(Ok((parse_elapsed, usage, answer))) => record(solution, part, parse_elapsed, usage, answer),
+        Ok(Err(err)) => {
+            if first {
+                show_parse_error(solution.day(), &err, &day.source);
+            }
+            failed(solution, part, Status::Error(err.to_string()))
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            failed(solution, part, Status::TimedOut(timeout.expect("only waiting with a timeout times out")))
+        }
+        Err(RecvTimeoutError::Disconnected) => failed(solution, part, Status::Error(String::from("solution panicked"))),
+    }
+}
+
+/// Show where the input stops making sense to the parser, if that is why solving failed.
+fn show_parse_error(day: usize, err: &PuzzleError, source: &str) {
+    if matches!(err, PuzzleError::Parse { .. }) {
+        eprintln!("{} Day {day}: {}", "error:".red().bold(), err.annotate(source));
+    }
+}
+
+/// Record of a part that was solved, or failed to solve.
+fn record(solution: &dyn AnySolution, part: usize, parse_elapsed: Duration, usage: Usage, answer: Result<String, PuzzleError>) -> Record {
+    let (answer, status) = match answer {
+        Ok(answer) => (answer, Status::Ok),
+        Err(err) => (String::new(), Status::Error(err.to_string())),
+    };
+    Record {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        answer,
+        parse_elapsed,
+        solve_elapsed: usage.elapsed,
+        allocations: cfg!(feature = "count-allocations").then_some(usage.allocations),
+        status,
+    }
+}
+
+/// Record of a part that could not be solved.
+fn failed(solution: &dyn AnySolution, part: usize, status: Status) -> Record {
+    Record {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        answer: String::new(),
+        parse_elapsed: Duration::ZERO,
+        solve_elapsed: Duration::ZERO,
+        allocations: None,
+        status,
+    }
 }
 
-fn read_input(path: impl AsRef<Path>) -> String {
-    std::fs::read_to_string(Path::new("input").join(path)).unwrap()
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+    assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+    assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+    assert!(parse_duration("2h").is_err());
+    assert!(parse_duration("-1s").is_err());
 }
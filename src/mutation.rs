@@ -0,0 +1,84 @@
+//! Random corruption of puzzle inputs, to check that solutions fail with an error instead of panicking.
+
+use crate::rng::Rng;
+
+/// Numbers that tend to break assumptions, such as zero, negative numbers and numbers that overflow.
+const NUMBERS: [&str; 9] = ["0", "1", "-1", "2", "99", "1000", "65536", "4294967296", "18446744073709551616"];
+
+/// Text that tends to break parsers.
+const TOKENS: [&str; 10] = [" ", "\n", "\n\n", ",", ":", "-", "[", "]", "x", "é"];
+
+/// Copy of the input with one to three random mutations, such as deleting text, changing a number or duplicating a line.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..rng.range(1..=3) {
+        match rng.range(0..=7) {
+            0 => {
+                // Delete a few characters.
+                let start = rng.range(0..=chars.len());
+                let end = (start + rng.range(1..=8)).min(chars.len());
+                chars.drain(start..end);
+            }
+            1 => {
+                let at = rng.range(0..=chars.len());
+                chars.splice(at..at, rng.choose(&TOKENS).chars());
+            }
+            2 => {
+                // Replace a number, or the digit at which it ends.
+                let digits: Vec<usize> = (0..chars.len()).filter(|&idx| chars[idx].is_ascii_digit()).collect();
+                if !digits.is_empty() {
+                    let end = digits[rng.index(digits.len())] + 1;
+                    let start = (0..end).rev().find(|&idx| !chars[idx].is_ascii_digit()).map_or(0, |idx| idx + 1);
+                    chars.splice(start..end, rng.choose(&NUMBERS).chars());
+                }
+            }
+            3 => {
+                // Replace a character with another one of the input, so that the input stays plausible.
+                if !chars.is_empty() {
+                    let (at, other) = (rng.index(chars.len()), rng.index(chars.len()));
+                    chars[at] = chars[other];
+                }
+            }
+            4 => chars.truncate(rng.range(0..=chars.len())),
+            _ => {
+                let mut lines = lines(&chars);
+                if !lines.is_empty() {
+                    let line = rng.index(lines.len());
+                    match rng.range(0..=2) {
+                        0 => lines.insert(line, lines[line].clone()),
+                        1 => {
+                            lines.remove(line);
+                        }
+                        _ => {
+                            let other = rng.index(lines.len());
+                            lines.swap(line, other);
+                        }
+                    }
+                }
+                chars = lines.concat();
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Lines of the characters, each with its line ending if it has one.
+fn lines(chars: &[char]) -> Vec<Vec<char>> {
+    chars.split_inclusive(|&c| c == '\n').map(<[char]>::to_vec).collect()
+}
+
+#[test]
+fn test_reproducible() {
+    let input = "move 1 from 2 to 1\nmove 3 from 1 to 3\n";
+    for seed in 0..100 {
+        assert_eq!(mutate(&mut Rng::new(seed), input), mutate(&mut Rng::new(seed), input));
+    }
+    assert!((0..100).any(|seed| mutate(&mut Rng::new(seed), input) != input));
+}
+
+#[test]
+fn test_empty() {
+    for seed in 0..100 {
+        mutate(&mut Rng::new(seed), "");
+    }
+}
@@ -0,0 +1,146 @@
+//! Prelude for parsers of puzzle input.
+//!
+//! Parsers fail with [`ParseError`], which describes what was expected where. Parsers backtrack over most errors, such as
+//! when `many1` stops at the first line that does not parse, so the error that ends parsing is often not the one that
+//! explains what is wrong. Every error is therefore also recorded as it is made, and [`parse_all`] reports the error
+//! furthest into the input.
+
+#![allow(unused_imports)]
+
+use crate::error::PuzzleError;
+pub use nom::Finish;
+pub use nom::branch::*;
+pub use nom::bytes::complete::*;
+pub use nom::character::complete::*;
+pub use nom::character::*;
+pub use nom::combinator::*;
+use nom::error::ContextError;
+use nom::error::ErrorKind;
+use nom::error::FromExternalError;
+pub use nom::error::context;
+pub use nom::multi::*;
+pub use nom::sequence::*;
+use std::cell::Cell;
+use std::fmt;
+
+/// Result of a parser of puzzle input.
+pub type IResult<I, O> = nom::IResult<I, O, ParseError<I>>;
+
+thread_local! {
+    /// Furthest error made since the start of [`parse_all`], by the length of the input remaining where it was made.
+    static FURTHEST: Cell<Option<(usize, Expected)>> = const { Cell::new(None) };
+}
+
+/// Error of a parser of puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError<I> {
+    /// Input remaining where parsing failed.
+    pub input: I,
+    pub expected: Expected,
+}
+
+/// What a parser expected to find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// Some literal text, named by a [`context`].
+    Text(&'static str),
+    Char(char),
+    Kind(ErrorKind),
+}
+impl Expected {
+    /// Whether this names what was expected, rather than the kind of parser that failed.
+    fn is_specific(&self) -> bool {
+        !matches!(self, Expected::Kind(_))
+    }
+}
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Text(text) => write!(f, "`{text}`"),
+            Expected::Char(c) => write!(f, "`{}`", c.escape_debug()),
+            Expected::Kind(kind) => f.write_str(match kind {
+                ErrorKind::Eof => "end of input",
+                ErrorKind::CrLf => "line ending",
+                ErrorKind::Digit => "digits",
+                ErrorKind::Alpha => "letters",
+                ErrorKind::AlphaNumeric => "letters or digits",
+                ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+                ErrorKind::Verify => "valid input",
+                _ => kind.description(),
+            }),
+        }
+    }
+}
+
+impl<'a> ParseError<&'a str> {
+    pub fn new(input: &'a str, expected: Expected) -> Self {
+        let error = Self { input, expected };
+        error.record();
+        error
+    }
+
+    /// Record this error if it is further into the input than any error before it, or says more about what was expected at
+    /// the same place.
+    fn record(&self) {
+        let remaining = self.input.len();
+        let further = match FURTHEST.get() {
+            None => true,
+            Some((furthest, expected)) => remaining < furthest || (remaining == furthest && !expected.is_specific() && self.expected.is_specific()),
+        };
+        if further {
+            FURTHEST.set(Some((remaining, self.expected)));
+        }
+    }
+}
+impl<'a> nom::error::ParseError<&'a str> for ParseError<&'a str> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::new(input, Expected::Kind(kind))
+    }
+
+    /// Keep the error of the inner parser, which is more precise.
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self::new(input, Expected::Char(c))
+    }
+
+    /// Keep the error that is furthest into the input.
+    fn or(self, other: Self) -> Self {
+        if self.input.len() < other.input.len() { self } else { other }
+    }
+}
+impl<'a> ContextError<&'a str> for ParseError<&'a str> {
+    /// Name what was expected by the context, if the inner parser failed right where the context starts.
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() {
+            Self::new(input, Expected::Text(context))
+        } else {
+            other
+        }
+    }
+}
+impl<'a, E> FromExternalError<&'a str, E> for ParseError<&'a str> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        Self::new(input, Expected::Kind(kind))
+    }
+}
+
+/// Recognize the given text, like nom's `tag`, and name it as what was expected if it is not found.
+pub fn tag<'a>(text: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    context(text, nom::bytes::complete::tag(text))
+}
+
+/// Run a parser on the whole input, failing if it does not consume all of it.
+///
+/// Fails with the error furthest into the input, which is where the input stops making sense to the parser.
+pub fn parse_all<'a, O>(input: &'a str, parser: impl FnMut(&'a str) -> IResult<&'a str, O>) -> Result<O, PuzzleError> {
+    let outer = FURTHEST.take();
+    let result = all_consuming(parser)(input).finish();
+    let furthest = FURTHEST.replace(outer);
+    result.map(|(_rest, output)| output).map_err(|err| match furthest {
+        Some((remaining, expected)) if remaining <= input.len() => PuzzleError::parse(input, &input[input.len() - remaining..], expected),
+        _ => PuzzleError::parse(input, err.input, err.expected),
+    })
+}
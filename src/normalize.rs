@@ -0,0 +1,35 @@
+//! Normalization of puzzle input before parsing.
+//!
+//! Parsers expect Unix line endings, with every line ending in a newline. Inputs saved by other tools may start with a
+//! UTF-8 byte order mark, have Windows line endings, or lack the final newline, and are normalized to the same text.
+
+use std::borrow::Cow;
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Input without a byte order mark, with `\r\n` line endings replaced by `\n`, and ending in a newline unless empty.
+///
+/// Borrows the input if it is already normalized.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(input);
+    let has_final_newline = input.is_empty() || input.ends_with('\n');
+    if !input.contains("\r\n") && has_final_newline {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = input.replace("\r\n", "\n");
+    if !normalized.is_empty() && !normalized.ends_with('\n') {
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+#[test]
+fn test_normalize() {
+    assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+    assert!(matches!(normalize(""), Cow::Borrowed("")));
+    assert_eq!(normalize("a\nb"), "a\nb\n");
+    assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+    assert_eq!(normalize("\u{feff}a\nb\n"), "a\nb\n");
+    assert_eq!(normalize("\u{feff}a\r\n\r\nb"), "a\n\nb\n");
+}
@@ -0,0 +1,74 @@
+//! Parameters of solutions that can be set by name, such as sizes that differ between the examples and the real input.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParamError {
+    #[error("unknown parameter `{name}`, expected one of: {expected}")]
+    Unknown { name: String, expected: String },
+
+    #[error("invalid value `{value}` for parameter `{name}`: {reason}")]
+    Value { name: String, value: String, reason: String },
+}
+
+/// Parameters of a solution, which default to those of the real puzzle input.
+pub trait Params: Default + Sync {
+    /// Names of the parameters that can be set.
+    const NAMES: &'static [&'static str];
+
+    /// Set a parameter from its value as text.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// Default parameters, with the given `(name, value)` assignments applied in order.
+    fn with(assignments: &[(&str, &str)]) -> Result<Self, ParamError> {
+        let mut params = Self::default();
+        for (name, value) in assignments {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// No parameters.
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(unknown::<Self>(name))
+    }
+}
+
+/// Error for a name that is not one of the parameters of `P`.
+pub fn unknown<P: Params>(name: &str) -> ParamError {
+    ParamError::Unknown {
+        name: name.to_owned(),
+        expected: if P::NAMES.is_empty() { "(none)".to_owned() } else { P::NAMES.join(", ") },
+    }
+}
+
+/// Parse the value of a parameter.
+pub fn parse<T>(name: &str, value: &str) -> Result<T, ParamError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|err: T::Err| ParamError::Value {
+        name: name.to_owned(),
+        value: value.to_owned(),
+        reason: err.to_string(),
+    })
+}
+
+#[test]
+fn test_with() {
+    assert_eq!(<()>::with(&[]), Ok(()));
+    assert_eq!(
+        <()>::with(&[("y", "10")]),
+        Err(ParamError::Unknown {
+            name: "y".to_owned(),
+            expected: "(none)".to_owned(),
+        })
+    );
+    assert!(matches!(parse::<i64>("y", "ten"), Err(ParamError::Value { .. })));
+}
@@ -0,0 +1,70 @@
+//! Minimal thread pool for running independent tasks.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread;
+
+/// Apply `f` to every item on `jobs` threads, and pass the results to `on_result` in the order of the items.
+///
+/// Results are passed on as soon as all results for preceding items are done.
+pub fn map_ordered<T, R>(items: &[T], jobs: NonZeroUsize, f: impl Fn(&T) -> R + Sync, mut on_result: impl FnMut(R))
+where
+    T: Sync,
+    R: Send,
+{
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, R)>();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(items.len()) {
+            let sender = sender.clone();
+            let next_item = &next_item;
+            let f = &f;
+            scope.spawn(move || {
+                loop {
+                    let idx = next_item.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(idx) else {
+                        break;
+                    };
+                    if sender.send((idx, f(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Results that are done, but wait for results of preceding items.
+        let mut pending: BTreeMap<usize, R> = BTreeMap::new();
+        let mut next_result = 0;
+        for (idx, result) in receiver {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next_result) {
+                on_result(result);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+#[test]
+fn test_map_ordered() {
+    let items: Vec<u64> = (0..100).collect();
+    for jobs in [1, 3, 200] {
+        let mut results = vec![];
+        map_ordered(
+            &items,
+            NonZeroUsize::new(jobs).unwrap(),
+            |&n| {
+                // Later items finish sooner.
+                thread::sleep(std::time::Duration::from_micros(100 - n));
+                n * 2
+            },
+            |r| results.push(r),
+        );
+        assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,290 @@
+//! Output of the results of solving puzzles, for humans or for scripts.
+
+use advent_of_code::alloc_stats::AllocStats;
+use clap::ValueEnum;
+use colored::Colorize;
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Colored, human readable lines.
+    #[default]
+    Text,
+
+    /// One JSON object per line.
+    Json,
+
+    /// Comma separated values with a header line.
+    Csv,
+}
+
+/// Result of solving one part of a puzzle.
+///
+/// The answer is empty if the part could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+
+    /// Time to parse the input, which is shared by all parts of the day.
+    pub parse_elapsed: Duration,
+
+    /// Time to solve the part from the parsed input.
+    pub solve_elapsed: Duration,
+
+    /// Allocations made while solving the part, if allocations are counted.
+    pub allocations: Option<AllocStats>,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The part was solved.
+    Ok,
+
+    /// The part could not be solved, e.g. because the input could not be read.
+    Error(String),
+
+    /// Solving the part was given up after the given timeout.
+    TimedOut(Duration),
+}
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error(_) => "error",
+            Status::TimedOut(_) => "timeout",
+        }
+    }
+
+    fn error(&self) -> String {
+        match self {
+            Status::Ok => String::new(),
+            Status::Error(message) => message.clone(),
+            Status::TimedOut(timeout) => format!("timed out after {timeout:?}"),
+        }
+    }
+}
+
+/// Writes records to stdout in a given format.
+#[derive(Debug)]
+pub struct Reporter {
+    format: Format,
+    wrote_header: bool,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Self { format, wrote_header: false }
+    }
+
+    pub fn report(&mut self, record: &Record) {
+        if self.format == Format::Csv && !self.wrote_header {
+            println!("year,day,part,status,answer,parse_ns,solve_ns,error,allocations,allocated_bytes,peak_bytes");
+            self.wrote_header = true;
+        }
+        println!("{}", self.format_record(record));
+    }
+
+    fn format_record(&self, record: &Record) -> String {
+        let Record {
+            year,
+            day,
+            part,
+            answer,
+            parse_elapsed,
+            solve_elapsed,
+            allocations,
+            status,
+        } = record;
+        match self.format {
+            Format::Text => match status {
+                Status::Ok => format!(
+                    "parse {:>8}  solve {:>8}{}    Day {day} Part {part}: {answer}",
+                    format_elapsed(*parse_elapsed),
+                    format_elapsed(*solve_elapsed),
+                    allocations.map(format_allocations).unwrap_or_default()
+                ),
+                Status::Error(message) => format!("{:>30}    Day {day} Part {part}: {}", "error".red().bold(), message.red()),
+                Status::TimedOut(_) => format!("{:>30}    Day {day} Part {part}: {}", "timeout".yellow().bold(), status.error().yellow()),
+            },
+            Format::Json => {
+                let error = match status {
+                    Status::Ok => String::new(),
+                    Status::Error(_) | Status::TimedOut(_) => format!(r#","error":{}"#, json_string(&status.error())),
+                };
+                let allocations = match allocations {
+                    Some(stats) => format!(
+                        r#","allocations":{},"allocated_bytes":{},"peak_bytes":{}"#,
+                        stats.allocations, stats.bytes, stats.peak_bytes
+                    ),
+                    None => String::new(),
+                };
+                format!(
+                    r#"{{"year":{year},"day":{day},"part":{part},"status":"{}","answer":{},"parse_ns":{},"solve_ns":{}{allocations}{error}}}"#,
+                    status.as_str(),
+                    json_string(answer),
+                    parse_elapsed.as_nanos(),
+                    solve_elapsed.as_nanos()
+                )
+            }
+            Format::Csv => format!(
+                "{year},{day},{part},{},{},{},{},{},{}",
+                status.as_str(),
+                csv_field(answer),
+                parse_elapsed.as_nanos(),
+                solve_elapsed.as_nanos(),
+                csv_field(&status.error()),
+                allocations.map_or(String::from(",,"), |stats| format!(
+                    "{},{},{}",
+                    stats.allocations, stats.bytes, stats.peak_bytes
+                ))
+            ),
+        }
+    }
+}
+
+/// Elapsed time, colored by how long it is.
+fn format_elapsed(elapsed: Duration) -> colored::ColoredString {
+    let micros = elapsed.as_micros();
+    match micros {
+        x if x < 10_000 => format!("{}μs", micros).green(),
+        x if x < 10_000_000 => format!("{}ms", micros / 1000).yellow(),
+        _ => format!("{}s", micros / 1000 / 1000).red(),
+    }
+}
+
+/// Allocation count, total bytes and peak bytes, as columns after the timings.
+fn format_allocations(stats: AllocStats) -> String {
+    format!(
+        "  allocs {:>9}  bytes {:>9}  peak {:>9}",
+        stats.allocations,
+        format_bytes(stats.bytes),
+        format_bytes(stats.peak_bytes)
+    )
+}
+
+/// Number of bytes in the largest binary unit that keeps it at least 1.
+fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < units.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.1}{}", units[unit])
+    }
+}
+
+/// Quote and escape a string as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quote a CSV field if it contains characters that need quoting.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[test]
+fn test_json_string() {
+    assert_eq!(json_string("FWSHSPJWM"), r#""FWSHSPJWM""#);
+    assert_eq!(json_string("\n#.\"\\\u{1}"), r#""\n#.\"\\\u0001""#);
+}
+
+#[test]
+fn test_csv_field() {
+    assert_eq!(csv_field("1514285714288"), "1514285714288");
+    assert_eq!(csv_field("\n#.\n"), "\"\n#.\n\"");
+    assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+}
+
+#[test]
+fn test_format_record() {
+    let record = Record {
+        year: 2022,
+        day: 7,
+        part: 2,
+        answer: String::from("24933642"),
+        parse_elapsed: Duration::from_micros(250),
+        solve_elapsed: Duration::from_micros(1500),
+        allocations: None,
+        status: Status::Ok,
+    };
+    assert_eq!(
+        Reporter::new(Format::Json).format_record(&record),
+        r#"{"year":2022,"day":7,"part":2,"status":"ok","answer":"24933642","parse_ns":250000,"solve_ns":1500000}"#
+    );
+    assert_eq!(
+        Reporter::new(Format::Csv).format_record(&record),
+        "2022,7,2,ok,24933642,250000,1500000,,,,"
+    );
+
+    let record = Record {
+        answer: String::new(),
+        parse_elapsed: Duration::ZERO,
+        solve_elapsed: Duration::ZERO,
+        status: Status::Error(String::from("could not read input file, \"input/day_07.txt\"")),
+        ..record
+    };
+    assert_eq!(
+        Reporter::new(Format::Json).format_record(&record),
+        r#"{"year":2022,"day":7,"part":2,"status":"error","answer":"","parse_ns":0,"solve_ns":0,"error":"could not read input file, \"input/day_07.txt\""}"#
+    );
+    assert_eq!(
+        Reporter::new(Format::Csv).format_record(&record),
+        r#"2022,7,2,error,,0,0,"could not read input file, ""input/day_07.txt""",,,"#
+    );
+
+    let record = Record {
+        answer: String::from("24933642"),
+        allocations: Some(AllocStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 2048,
+        }),
+        status: Status::Ok,
+        ..record
+    };
+    assert_eq!(
+        Reporter::new(Format::Json).format_record(&record),
+        r#"{"year":2022,"day":7,"part":2,"status":"ok","answer":"24933642","parse_ns":0,"solve_ns":0,"allocations":12,"allocated_bytes":4096,"peak_bytes":2048}"#
+    );
+    assert_eq!(
+        Reporter::new(Format::Csv).format_record(&record),
+        "2022,7,2,ok,24933642,0,0,,12,4096,2048"
+    );
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(0), "0B");
+    assert_eq!(format_bytes(1023), "1023B");
+    assert_eq!(format_bytes(1536), "1.5KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024), "3.0MiB");
+}
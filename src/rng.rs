@@ -0,0 +1,98 @@
+//! Deterministic pseudorandom numbers, so that anything generated from them can be reproduced from the seed.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64 pseudorandom number generator.
+///
+/// Not suitable for cryptography, but fast, and the same on every platform for the same seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed number in the range.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the range is empty.
+    pub fn range<T: Copy + TryInto<i128> + TryFrom<i128>>(&mut self, range: RangeInclusive<T>) -> T {
+        let (Ok(start), Ok(end)) = ((*range.start()).try_into(), (*range.end()).try_into()) else {
+            unreachable!("every primitive integer fits in an i128")
+        };
+        assert!(start <= end, "empty range");
+        let len = (end - start + 1) as u128;
+        // Multiply-shift maps 64 random bits onto the range with negligible bias.
+        let offset = (self.next_u64() as u128 * len) >> 64;
+        let Ok(value) = T::try_from(start + offset as i128) else {
+            unreachable!("value is within the range")
+        };
+        value
+    }
+
+    /// Uniformly distributed index into a slice of the given length.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the length is zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len - 1)
+    }
+
+    /// True with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+
+    /// Uniformly chosen item of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the slice is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffle a slice in place, with every order equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0..=idx));
+        }
+    }
+}
+
+#[test]
+fn test_reproducible() {
+    let numbers = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(7), numbers(7));
+    assert_ne!(numbers(7), numbers(8));
+}
+
+#[test]
+fn test_range() {
+    let mut rng = Rng::new(0);
+    let samples: Vec<i64> = (0..1000).map(|_| rng.range(-3..=3)).collect();
+    assert!(samples.iter().all(|n| (-3..=3).contains(n)));
+    assert!((-3..=3).all(|n| samples.contains(&n)));
+    assert_eq!(rng.range(u64::MAX..=u64::MAX), u64::MAX);
+
+    let mut items: Vec<usize> = (0..20).collect();
+    rng.shuffle(&mut items);
+    items.sort_unstable();
+    assert!(items.iter().copied().eq(0..20));
+}
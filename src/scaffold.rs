@@ -0,0 +1,190 @@
+//! Scaffolding for the solution of a new day.
+
+use crate::answers;
+use crate::input;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Skeleton of a solution, with `<year>`, `<n>`, `<nn>` and `<title>` to be filled in.
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Create the module of a new day from the template in the crate at `root`, register it, and create an empty input file.
+///
+/// Creates and registers the module of the year and an empty answers file too, if it is the first day of the year.
+/// Fails without changing anything if the module of the day already exists.
+pub fn new_day(root: &Path, year: usize, day: usize, title: &str) -> io::Result<()> {
+    let module_path = root.join(module_path(year, day));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    let year_path = root.join("src").join(format!("year_{year}.rs"));
+    let mut changes = Vec::new();
+    if year_path.exists() {
+        let year_module = read(&year_path)?;
+        let year_module = insert_sorted(&year_module, "    day_", &format!("    day_{day:02}::Day{day:02},"))?;
+        changes.push((year_path, year_module));
+    } else {
+        let lib_path = root.join("src/lib.rs");
+        let lib = read(&lib_path)?;
+        let lib = insert_sorted(&lib, "    year_", &format!("    year_{year},"))?;
+
+        changes.push((year_path, render_year(year, day)));
+        changes.push((lib_path, lib));
+    }
+
+    fs::create_dir_all(module_path.parent().expect("module is in a directory"))?;
+    write(&module_path, &render(year, day, title))?;
+    println!("Created {}", module_path.display());
+    for (path, contents) in changes {
+        write(&path, &contents)?;
+    }
+
+    let input_path = root.join(input::default_path(year, day));
+    if !input_path.exists() {
+        fs::create_dir_all(root.join(input::year_dir(year)))?;
+        write(&input_path, "")?;
+        println!("Created {}", input_path.display());
+    }
+
+    let answers_path = root.join(answers::default_path(year));
+    if !answers_path.exists() {
+        write(&answers_path, "")?;
+        println!("Created {}", answers_path.display());
+    }
+
+    Ok(())
+}
+
+fn module_path(year: usize, day: usize) -> PathBuf {
+    Path::new("src").join(format!("year_{year}")).join(format!("day_{day:02}.rs"))
+}
+
+/// Fill in the template for a given day.
+fn render(year: usize, day: usize, title: &str) -> String {
+    TEMPLATE
+        .replace("\"<title>\"", &format!("{title:?}"))
+        .replace("<title>", title)
+        .replace("<year>", &year.to_string())
+        .replace("<nn>", &format!("{day:02}"))
+        .replace("<n>", &day.to_string())
+}
+
+/// Module of a new year, with the given day as its only solution.
+fn render_year(year: usize, day: usize) -> String {
+    format!("//! Solutions to the puzzles of Advent of Code {year}.\n\ncrate::solution::days! {{\n    day_{day:02}::Day{day:02},\n}}\n")
+}
+
+/// Insert `line` among the consecutive lines starting with `prefix`, keeping them sorted.
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> io::Result<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let Some(first) = lines.iter().position(|l| l.starts_with(prefix)) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("no line starts with `{prefix}`")));
+    };
+    let end = lines[first..]
+        .iter()
+        .position(|l| !l.starts_with(prefix))
+        .map_or(lines.len(), |n| first + n);
+    let at = lines[first..end].iter().position(|&l| l > line).map_or(end, |n| first + n);
+    lines.insert(at, line);
+
+    let mut result = lines.join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|err| io::Error::new(err.kind(), format!("could not read {}: {err}", path.display())))
+}
+
+fn write(path: &Path, contents: &str) -> io::Result<()> {
+    fs::write(path, contents).map_err(|err| io::Error::new(err.kind(), format!("could not write {}: {err}", path.display())))
+}
+
+#[test]
+fn test_render() {
+    let module = render(2022, 22, "Monkey Map");
+    assert!(module.starts_with("//! Day 22: Monkey Map\n//!\n//! https://adventofcode.com/2022/day/22\n"));
+    assert!(module.contains("pub struct Day22;\nimpl Solution for Day22 {"));
+    assert!(module.contains("        2022\n"));
+    assert!(module.contains("        22\n"));
+    assert!(module.contains("        \"Monkey Map\"\n"));
+    assert!(!module.contains("<year>") && !module.contains("<n>") && !module.contains("<nn>") && !module.contains("<title>"));
+}
+
+#[test]
+fn test_insert_sorted() {
+    let source = "pub mod day_01;\npub mod day_03;\npub mod error;\n";
+    assert_eq!(
+        insert_sorted(source, "pub mod day_", "pub mod day_02;").unwrap(),
+        "pub mod day_01;\npub mod day_02;\npub mod day_03;\npub mod error;\n"
+    );
+    assert_eq!(
+        insert_sorted(source, "pub mod day_", "pub mod day_04;").unwrap(),
+        "pub mod day_01;\npub mod day_03;\npub mod day_04;\npub mod error;\n"
+    );
+}
+
+#[test]
+fn test_render_year() {
+    let source = render_year(2023, 1);
+    assert_eq!(
+        source,
+        "//! Solutions to the puzzles of Advent of Code 2023.\n\ncrate::solution::days! {\n    day_01::Day01,\n}\n"
+    );
+    assert!(
+        insert_sorted(&source, "    day_", "    day_03::Day03,")
+            .unwrap()
+            .ends_with("days! {\n    day_01::Day01,\n    day_03::Day03,\n}\n")
+    );
+}
+
+/// Scaffold a day of an existing year and the first day of a new year in a copy of the crate, and run the tests of the
+/// registry there.
+#[test]
+fn new_days_pass_the_registry_tests() {
+    fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+            } else {
+                fs::copy(entry.path(), to.join(entry.file_name()))?;
+            }
+        }
+        Ok(())
+    }
+
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Kept in the target directory, so that the dependencies are only built once.
+    let scaffold_dir = crate_dir.join("target/scaffold");
+    let root = scaffold_dir.join("crate");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    copy_dir(&crate_dir.join("src"), &root.join("src")).unwrap();
+    for file in ["Cargo.toml", "Cargo.lock", "rust-toolchain.toml"] {
+        fs::copy(crate_dir.join(file), root.join(file)).unwrap();
+    }
+
+    new_day(&root, 2022, 22, "Monkey Map").unwrap();
+    new_day(&root, 2023, 1, "Trebuchet?!").unwrap();
+    assert!(new_day(&root, 2023, 1, "Trebuchet?!").is_err());
+
+    let output = std::process::Command::new(env!("CARGO"))
+        .args(["test", "--lib", "solution::"])
+        .current_dir(&root)
+        .env("CARGO_TARGET_DIR", scaffold_dir.join("target"))
+        .env("RUSTFLAGS", "-D warnings")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+}
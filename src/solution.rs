@@ -0,0 +1,374 @@
+pub use crate::YEARS;
+use crate::alloc_stats;
+use crate::alloc_stats::AllocStats;
+use crate::error::PuzzleError;
+use crate::normalize::normalize;
+use crate::params::Params;
+use crate::rng::Rng;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Declare the module of every year, and register their solutions as `YEARS`, ordered by year.
+///
+/// Used once, in the crate root, which is the only place where a year is registered.
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        /// Solutions of every year, ordered by year.
+        pub static YEARS: &[&[&dyn $crate::solution::AnySolution]] = &[$($year::SOLUTIONS),*];
+    };
+}
+pub(crate) use years;
+
+/// Declare the module of every day of a year, and register their solutions as `SOLUTIONS`, ordered by day.
+///
+/// Used once in the module of every year, which is the only place where a day is registered.
+macro_rules! days {
+    ($($day:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        /// Every solution of the year, ordered by day.
+        pub static SOLUTIONS: &[&dyn $crate::solution::AnySolution] = &[$(&$day::$solution),*];
+    };
+}
+pub(crate) use days;
+
+/// Solution to the puzzle of one day.
+///
+/// The input is parsed once, and the parsed input is shared by both parts.
+pub trait Solution: Sync {
+    /// Parsed puzzle input, which may borrow from the input text, and is shared by parts solved on different threads.
+    type Input<'i>: Sync;
+
+    /// Parameters of the puzzle, which default to those of the real puzzle input.
+    type Params: Params;
+
+    /// Year of the event that the puzzle is from.
+    fn year(&self) -> usize;
+
+    /// Day of the puzzle, starting from 1.
+    fn day(&self) -> usize;
+
+    /// Title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Examples from the puzzle description.
+    fn examples(&self) -> &'static [Example];
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError>;
+
+    /// Problems with the parsed input that break assumptions of the puzzle, which the parts rely on.
+    ///
+    /// Inputs with problems are not solved.
+    fn validate(&self, _input: &Self::Input<'_>) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Random puzzle input that parses and has no problems, so that it can be solved with the default parameters.
+    ///
+    /// What `size` counts is up to the puzzle, such as lines or monkeys.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+
+    fn part_1(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError>;
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError>;
+}
+
+/// Solution of any day, with the type of its parsed input erased.
+///
+/// Implemented for every [`Solution`].
+pub trait AnySolution: Sync {
+    /// Year of the event that the puzzle is from.
+    fn year(&self) -> usize;
+
+    /// Day of the puzzle, starting from 1.
+    fn day(&self) -> usize;
+
+    /// Title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Examples from the puzzle description.
+    fn examples(&self) -> &'static [Example];
+
+    /// Names of the parameters of the puzzle.
+    fn param_names(&self) -> &'static [&'static str];
+
+    /// Parse the input and list every problem that breaks an assumption of the puzzle, without solving it.
+    ///
+    /// The input is normalized first, like for solving.
+    ///
+    /// Fails if the input cannot be parsed.
+    fn check_input(&self, input: &str) -> Result<Vec<String>, PuzzleError>;
+
+    /// Random puzzle input that parses and has no problems, of a size that is up to the puzzle.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+
+    /// Parse the input once and solve the given parts in order, which are either 1 or 2.
+    ///
+    /// The input is [normalized](normalize) first, so that line endings and a byte order mark do not matter.
+    /// The parameters default to those of the real puzzle input, with the given `(name, value)` assignments applied in order.
+    /// Passes the part, what it took to solve it and its answer to `on_part`, and returns the time to parse the input.
+    /// Fails without solving any part if a parameter cannot be set, or the input cannot be parsed or has problems.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if a part is neither 1 nor 2.
+    fn solve(
+        &self,
+        input: &str,
+        params: &[(&str, &str)],
+        parts: &[usize],
+        on_part: &mut dyn FnMut(usize, Usage, Result<String, PuzzleError>),
+    ) -> Result<Duration, PuzzleError>;
+
+    /// Parse the input like [`solve`](Self::solve), and pass the time it took and a function that solves a part of it
+    /// to `with_parsed`, which may call the function from several threads at once.
+    ///
+    /// # Panics
+    ///
+    /// The function will panic if a part is neither 1 nor 2.
+    fn with_parsed(&self, input: &str, params: &[(&str, &str)], with_parsed: &mut dyn FnMut(Parsed<'_>));
+}
+
+/// Solve a part of a parsed input, and return what it took along with the answer.
+pub type SolvePart<'p> = dyn Fn(usize) -> (Usage, Result<String, PuzzleError>) + Sync + 'p;
+
+/// Time it took to parse an input, along with a function that solves a part of it, or why it could not be parsed.
+pub type Parsed<'p> = Result<(Duration, &'p SolvePart<'p>), PuzzleError>;
+
+impl<S: Solution> AnySolution for S {
+    fn year(&self) -> usize {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> usize {
+        Solution::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solution::title(self)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Solution::examples(self)
+    }
+
+    fn param_names(&self) -> &'static [&'static str] {
+        S::Params::NAMES
+    }
+
+    fn check_input(&self, input: &str) -> Result<Vec<String>, PuzzleError> {
+        let input = normalize(input);
+        let parsed = self.parse(&input)?;
+        Ok(self.validate(&parsed))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        Solution::generate(self, rng, size)
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        params: &[(&str, &str)],
+        parts: &[usize],
+        on_part: &mut dyn FnMut(usize, Usage, Result<String, PuzzleError>),
+    ) -> Result<Duration, PuzzleError> {
+        let mut parse_elapsed = Ok(Duration::ZERO);
+        self.with_parsed(input, params, &mut |parsed| match parsed {
+            Ok((elapsed, solve_part)) => {
+                parse_elapsed = Ok(elapsed);
+                for &part in parts {
+                    let (usage, answer) = solve_part(part);
+                    on_part(part, usage, answer);
+                }
+            }
+            Err(err) => parse_elapsed = Err(err),
+        });
+        parse_elapsed
+    }
+
+    fn with_parsed(&self, input: &str, params: &[(&str, &str)], with_parsed: &mut dyn FnMut(Parsed<'_>)) {
+        let params = match S::Params::with(params) {
+            Ok(params) => params,
+            Err(err) => return with_parsed(Err(err.into())),
+        };
+
+        let now = Instant::now();
+        let input = normalize(input);
+        let parsed = match self.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => return with_parsed(Err(err)),
+        };
+        let problems = self.validate(&parsed);
+        if !problems.is_empty() {
+            return with_parsed(Err(PuzzleError::invalid(problems.join("; "))));
+        }
+        let parse_elapsed = now.elapsed();
+
+        with_parsed(Ok((parse_elapsed, &|part| {
+            let now = Instant::now();
+            let (answer, allocations) = alloc_stats::measure(|| match part {
+                1 => self.part_1(&parsed, &params),
+                2 => self.part_2(&parsed, &params),
+                _ => panic!("Day {} has no part {part}", Solution::day(self)),
+            });
+            let usage = Usage {
+                elapsed: now.elapsed(),
+                allocations,
+            };
+            (usage, answer)
+        })));
+    }
+}
+
+/// What it took to solve a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub elapsed: Duration,
+
+    /// Allocations made while solving, which are all zero unless allocations are counted.
+    pub allocations: AllocStats,
+}
+
+/// Example input from the puzzle description, with the answers given for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+
+    /// Answer of part 1, if the puzzle description gives it for this example.
+    pub part_1: Option<&'static str>,
+
+    /// Answer of part 2, if the puzzle description gives it for this example.
+    pub part_2: Option<&'static str>,
+
+    /// Parameters that differ from those of the real puzzle input, as `(name, value)` assignments.
+    pub params: &'static [(&'static str, &'static str)],
+}
+impl Example {
+    /// Answer of the given part, if known.
+    pub fn answer(&self, part: usize) -> Option<&'static str> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+}
+
+/// Solutions registered for a given year, ordered by day.
+pub fn solutions(year: usize) -> Option<&'static [&'static dyn AnySolution]> {
+    YEARS
+        .iter()
+        .copied()
+        .find(|solutions| solutions.first().is_some_and(|s| s.year() == year))
+}
+
+/// Find the solution registered for a given year and day.
+pub fn find(year: usize, day: usize) -> Option<&'static dyn AnySolution> {
+    solutions(year)?.iter().copied().find(|s| s.day() == day)
+}
+
+/// The latest year with solutions.
+pub fn latest_year() -> usize {
+    YEARS
+        .iter()
+        .filter_map(|solutions| solutions.first())
+        .map(|s| s.year())
+        .max()
+        .expect("no solutions registered")
+}
+
+#[test]
+fn registry_is_ordered_by_year_and_day() {
+    let years: Vec<usize> = YEARS.iter().map(|solutions| solutions[0].year()).collect();
+    assert!(years.is_sorted_by(|a, b| a < b));
+    for solutions in YEARS {
+        assert!(solutions.iter().all(|s| s.year() == solutions[0].year()));
+        let days: Vec<usize> = solutions.iter().map(|s| s.day()).collect();
+        assert!(days.is_sorted_by(|a, b| a < b));
+    }
+}
+
+#[test]
+fn example_names_are_unique_per_day() {
+    for solution in YEARS.iter().copied().flatten() {
+        let mut names: Vec<&str> = solution.examples().iter().map(|example| example.name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), solution.examples().len(), "day {}", solution.day());
+    }
+}
+
+#[test]
+fn examples_parse_the_same_when_not_normalized() {
+    for solution in YEARS.iter().copied().flatten() {
+        // Empty inputs have no lines to change.
+        for example in solution.examples().iter().filter(|example| !example.input.is_empty()) {
+            let lines: Vec<&str> = example.input.lines().collect();
+            let variants = [
+                ("windows line endings", lines.join("\r\n") + "\r\n"),
+                ("byte order mark", format!("\u{feff}{}", example.input)),
+                ("no final newline", lines.join("\n")),
+                ("all of the above", format!("\u{feff}{}", lines.join("\r\n"))),
+            ];
+            for (variant, input) in variants {
+                let context = format!("Day {} ({}) with {variant}", solution.day(), example.name);
+                assert_eq!(normalize(&input), normalize(example.input), "{context}");
+                assert_eq!(solution.check_input(&input), Ok(Vec::new()), "{context}");
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_are_valid() {
+    for solution in YEARS.iter().copied().flatten() {
+        for seed in 0..3 {
+            for size in [0, 1, 10, 40] {
+                let input = solution.generate(&mut Rng::new(seed), size);
+                let context = format!("Day {} of {} with seed {seed} and size {size}", solution.day(), solution.year());
+                assert_eq!(solution.generate(&mut Rng::new(seed), size), input, "{context} is not reproducible");
+                assert_eq!(solution.check_input(&input), Ok(Vec::new()), "{context}:\n{input}");
+            }
+        }
+    }
+}
+
+#[test]
+fn mutated_examples_do_not_panic() {
+    for solution in YEARS.iter().copied().flatten() {
+        for example in solution.examples() {
+            for seed in 0..200 {
+                let input = crate::mutation::mutate(&mut Rng::new(seed), example.input);
+                let context = format!(
+                    "Day {} of {} ({}) mutated with seed {seed}",
+                    solution.day(),
+                    solution.year(),
+                    example.name
+                );
+                let checked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| solution.check_input(&input)));
+                let Ok(checked) = checked else {
+                    panic!("{context} panicked while checking:\n{input}");
+                };
+                if checked != Ok(Vec::new()) {
+                    continue;
+                }
+
+                // Inputs that make a search much larger are cancelled, as only panics matter.
+                let token = crate::cancel::Token::default();
+                let timer = token.clone();
+                std::thread::spawn(move || {
+                    std::thread::sleep(Duration::from_millis(100));
+                    timer.cancel();
+                });
+                let solved = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    token.scope(|| solution.solve(&input, example.params, &[1, 2], &mut |_, _, _| {}))
+                }));
+                assert!(solved.is_ok(), "{context} panicked while solving:\n{input}");
+            }
+        }
+    }
+}
@@ -1,25 +1,64 @@
-//! Day n: <title>
+//! Day <n>: <title>
 //!
-//! https://adventofcode.com/2022/day/n
+//! https://adventofcode.com/<year>/day/<n>
 
-pub fn part_1(input: &str) -> usize {
-    parser::parse(input);
-    Default::default()
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+
+pub fn part_1(_input: &[()]) -> Result<usize, PuzzleError> {
+    Ok(Default::default())
 }
 
-pub fn part_2(input: &str) -> usize {
-    parser::parse(input);
-    Default::default()
+pub fn part_2(_input: &[()]) -> Result<usize, PuzzleError> {
+    Ok(Default::default())
 }
 
-mod parser {
-    use super::*;
+pub struct Day<nn>;
+impl Solution for Day<nn> {
+    type Input<'i> = Vec<()>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        <year>
+    }
+
+    fn day(&self) -> usize {
+        <n>
+    }
+
+    fn title(&self) -> &'static str {
+        "<title>"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+pub mod parser {
+    use crate::error::PuzzleError;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<()> {
-        all_consuming(terminated(separated_list1(line_ending, main_parser), multispace0))(s)
-            .unwrap()
-            .1
+    pub fn parse(s: &str) -> Result<Vec<()>, PuzzleError> {
+        parse_all(s, terminated(separated_list1(line_ending, main_parser), multispace0))
     }
 
     fn main_parser(s: &str) -> IResult<&str, ()> {
@@ -27,17 +66,36 @@ mod parser {
     }
 }
 
-#[cfg(test)]
-static EXAMPLE: &str = "\
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random puzzle input of `size` lines.
+    pub fn generate(_rng: &mut Rng, size: usize) -> String {
+        "\n".repeat(size)
+    }
+}
 
+// Only used by the tests until it is filled in and added to the examples, with the answers from the puzzle description.
+#[allow(dead_code)]
+static EXAMPLE: &str = "\
 ";
 
+static EXAMPLES: &[Example] = &[
+    // Example {
+    //     name: "example",
+    //     input: EXAMPLE,
+    //     part_1: Some("0"),
+    //     part_2: None,
+    //     params: &[],
+    // },
+];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 0);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 0);
 }
 
 // #[test]
 // fn part_2_example() {
-//     assert_eq!(part_2(EXAMPLE), 0);
+//     assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 0);
 // }
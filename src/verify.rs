@@ -0,0 +1,115 @@
+//! Check the answers of solutions against known answers.
+
+use crate::answers;
+use crate::answers::Answers;
+use crate::corpus;
+use crate::input;
+use advent_of_code::error::PuzzleError;
+use advent_of_code::solution::AnySolution;
+use advent_of_code::solution::Example;
+use colored::Colorize;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Solve both parts of every given solution and compare with the known answers.
+///
+/// Fails if any answer differs from its known answer, or if any input cannot be read or solved.
+/// Answers that are not known are reported, but do not fail verification.
+pub fn verify(solutions: &[&dyn AnySolution], answers: &Answers) -> ExitCode {
+    let mut failed = false;
+
+    for solution in solutions {
+        let day = solution.day();
+        let input = match input::read_input(solution.year(), day, None) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{} {err}", "error:".red().bold());
+                failed = true;
+                continue;
+            }
+        };
+
+        let parsed = solution.solve(&input, &[], &[1, 2], &mut |part, _usage, answer| {
+            if !check(&format!("Day {day} Part {part}"), answer, answers.get(day, part)) {
+                failed = true;
+            }
+        });
+        if let Err(err) = parsed {
+            failed = true;
+            println!("{:>10}    Day {day}: {}", "error".red().bold(), err.to_string().red());
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Solve the given parts of the given examples from the puzzle description and compare with their expected answers.
+///
+/// Parts of an example without an expected answer are skipped, as the example is not meant for them.
+/// The given parameters are applied after those of the example.
+pub fn verify_examples(solution: &dyn AnySolution, examples: &[Example], params: &[(&str, &str)], parts: &[usize]) -> ExitCode {
+    let mut failed = false;
+    let day = solution.day();
+
+    for example in examples {
+        let parts: Vec<usize> = parts.iter().copied().filter(|&part| example.answer(part).is_some()).collect();
+        let params: Vec<(&str, &str)> = example.params.iter().chain(params).copied().collect();
+        let parsed = solution.solve(example.input, &params, &parts, &mut |part, _usage, answer| {
+            if !check(&format!("Day {day} Part {part} ({})", example.name), answer, example.answer(part)) {
+                failed = true;
+            }
+        });
+        if let Err(err) = parsed {
+            failed = true;
+            println!("{:>10}    Day {day} ({}): {}", "error".red().bold(), example.name, err.to_string().red());
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Solve every case of the regression corpus of a year in `dir`, or only those of the given day, and check their expected
+/// answers.
+pub fn verify_corpus(dir: &Path, year: usize, day: Option<usize>) -> ExitCode {
+    let cases = match corpus::discover(dir, year) {
+        Ok(cases) => cases,
+        Err(err) => {
+            eprintln!("{} {err}", "error:".red().bold());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for case in cases.iter().filter(|case| day.is_none_or(|day| case.day == day)) {
+        let failures = corpus::check(case);
+        if failures.is_empty() {
+            println!("{:>10}    {}", "pass".green(), case.input_path.display());
+        }
+        for failure in failures {
+            failed = true;
+            println!("{:>10}    {failure}", "FAIL".red().bold());
+        }
+    }
+
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+}
+
+/// Print an answer next to its known answer, and whether it passes.
+///
+/// Returns false if the part could not be solved or the answer differs from the known answer.
+fn check(label: &str, answer: Result<String, PuzzleError>, known_answer: Option<&str>) -> bool {
+    let answer = match answer {
+        Ok(answer) => answer,
+        Err(err) => {
+            println!("{:>10}    {label}: {}", "error".red().bold(), err.to_string().red());
+            return false;
+        }
+    };
+    let (status, note, passed) = match known_answer {
+        Some(known_answer) if answers::matches(&answer, known_answer) => ("pass".green(), String::new(), true),
+        Some(known_answer) => ("FAIL".red().bold(), format!(" (expected {known_answer})"), false),
+        None => ("missing".yellow(), String::new(), true),
+    };
+    println!("{status:>10}    {label}: {answer}{note}");
+    passed
+}
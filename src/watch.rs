@@ -0,0 +1,40 @@
+//! Rerun solutions whenever their input file changes.
+
+use colored::Colorize;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+
+/// How often to check the input file for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Call `run` now, and again every time the modification time of `path` changes, until interrupted.
+///
+/// A panic in `run` is reported and does not stop watching, so that a solution can be fixed and rerun.
+pub fn watch(path: &Path, mut run: impl FnMut()) -> ! {
+    let mut last_modified = modified(path);
+    loop {
+        if panic::catch_unwind(panic::AssertUnwindSafe(&mut run)).is_err() {
+            eprintln!("{} solution panicked", "error:".red().bold());
+        }
+        println!("{} {} for changes", "Watching".blue().bold(), path.display());
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let modified = modified(path);
+            if modified != last_modified {
+                last_modified = modified;
+                break;
+            }
+        }
+        println!();
+    }
+}
+
+/// Modification time of a file, or `None` if it does not exist or cannot be read.
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
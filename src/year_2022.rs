@@ -0,0 +1,25 @@
+//! Solutions to the puzzles of Advent of Code 2022.
+
+crate::solution::days! {
+    day_01::Day01,
+    day_02::Day02,
+    day_03::Day03,
+    day_04::Day04,
+    day_05::Day05,
+    day_06::Day06,
+    day_07::Day07,
+    day_08::Day08,
+    day_09::Day09,
+    day_10::Day10,
+    day_11::Day11,
+    day_12::Day12,
+    day_13::Day13,
+    day_14::Day14,
+    day_15::Day15,
+    day_16::Day16,
+    day_17::Day17,
+    day_18::Day18,
+    day_19::Day19,
+    day_20::Day20,
+    day_21::Day21,
+}
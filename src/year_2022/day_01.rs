@@ -0,0 +1,145 @@
+//! Day 1: Calorie Counting
+//!
+//! https://adventofcode.com/2022/day/1
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::collections::BinaryHeap;
+
+pub fn part_1(elves: &[Elf]) -> Result<usize, PuzzleError> {
+    // For each elf, sum the calories of all the snacks he/she is carrying.
+    let calorie_sums = elves.iter().map(|elf| elf.total_calories());
+
+    // How many total calories is the elf that is carrying the most calories carrying?
+    calorie_sums.max().ok_or_else(|| PuzzleError::invalid("there are no elves"))
+}
+
+pub fn part_2(elves: &[Elf]) -> Result<usize, PuzzleError> {
+    // For each elf, put the total number of calories into a max heap.
+    let heap = elves.iter().map(|elf| elf.total_calories()).collect::<BinaryHeap<usize>>();
+
+    // Take out the three max calorie totals and sum them
+    let solution = heap.into_iter_sorted().take(3).sum();
+
+    debug_assert!(solution <= 3 * part_1(elves)?);
+
+    Ok(solution)
+}
+
+pub struct Day01;
+impl Solution for Day01 {
+    type Input<'i> = Vec<Elf>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+pub struct Elf {
+    /// Snacks carried by this elf.
+    snacks: Vec<usize>,
+}
+
+impl Elf {
+    /// Total number of callories carried by this elf
+    fn total_calories(&self) -> usize {
+        self.snacks.iter().sum()
+    }
+}
+
+pub mod parser {
+    use super::Elf;
+    use crate::error::PuzzleError;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Elf>, PuzzleError> {
+        parse_all(s, parse_elves)
+    }
+
+    fn parse_elves(s: &str) -> IResult<&str, Vec<Elf>> {
+        separated_list0(line_ending, parse_one_elf)(s)
+    }
+
+    fn parse_one_elf(s: &str) -> IResult<&str, Elf> {
+        let line_with_snack = terminated(map_res(digit1, str::parse), line_ending);
+        map(many1(line_with_snack), |snacks| Elf { snacks })(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` elves, at least one, each carrying a few snacks.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| (0..rng.range(1..=10)).map(|_| format!("{}\n", rng.range(1000..=60_000))).collect())
+            .collect();
+        elves.join("\n")
+    }
+}
+
+static EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("24000"),
+    part_2: Some("45000"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 24000);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 45000);
+}
@@ -2,9 +2,12 @@
 //!
 //! https://adventofcode.com/2022/day/2
 
-pub fn part_1(input: &str) -> usize {
-    let strategy_guide = parser::parse(input);
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 
+pub fn part_1(strategy_guide: &StrategyGuide) -> Result<usize, PuzzleError> {
     fn choose(s: &Strategy) -> RockPaperScissors {
         match s.us {
             EncryptedStrategy::X => RockPaperScissors::Rock,
@@ -13,19 +16,17 @@ pub fn part_1(input: &str) -> usize {
         }
     }
 
-    strategy_guide
+    Ok(strategy_guide
         .strategies
         .iter()
         .map(|s| {
             let us = choose(s);
             us.score_shape() + us.outcome(&s.them).score()
         })
-        .sum()
+        .sum())
 }
 
-pub fn part_2(input: &str) -> usize {
-    let strategy_guide = parser::parse(input);
-
+pub fn part_2(strategy_guide: &StrategyGuide) -> Result<usize, PuzzleError> {
     fn choose(s: &Strategy) -> RockPaperScissors {
         let us = match s.us {
             EncryptedStrategy::X => match s.them {
@@ -54,17 +55,55 @@ pub fn part_2(input: &str) -> usize {
         us
     }
 
-    strategy_guide
+    Ok(strategy_guide
         .strategies
         .iter()
         .map(|s| {
             let us: RockPaperScissors = choose(s);
             us.score_shape() + us.outcome(&s.them).score()
         })
-        .sum()
+        .sum())
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    type Input<'i> = StrategyGuide;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
 }
 
-struct StrategyGuide {
+pub struct StrategyGuide {
     strategies: Vec<Strategy>,
 }
 
@@ -126,12 +165,12 @@ impl RoundOutcome {
     }
 }
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> StrategyGuide {
-        all_consuming(parse_strategy_guide)(s).unwrap().1
+    pub fn parse(s: &str) -> Result<StrategyGuide, PuzzleError> {
+        parse_all(s, parse_strategy_guide)
     }
 
     fn parse_strategy_guide(s: &str) -> IResult<&str, StrategyGuide> {
@@ -172,19 +211,37 @@ mod parser {
     }
 }
 
-#[cfg(test)]
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random strategy guide of `size` rounds.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", rng.choose(&['A', 'B', 'C']), rng.choose(&['X', 'Y', 'Z'])))
+            .collect()
+    }
+}
+
 static EXAMPLE: &str = "\
 A Y
 B X
 C Z
 ";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("15"),
+    part_2: Some("12"),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 15);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 15);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE), 12);
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 12);
 }
@@ -0,0 +1,222 @@
+//! Day 3: Rucksack Reorganization
+//!
+//! https://adventofcode.com/2022/day/3
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::collections::BTreeSet;
+
+pub fn part_1(groups: &[Group]) -> Result<usize, PuzzleError> {
+    groups
+        .iter()
+        .flat_map(|group| group.rucksacks.iter())
+        .map(|rucksack| rucksack.common_item_between_compartments().map(priority))
+        .sum()
+}
+
+pub fn part_2(groups: &[Group]) -> Result<usize, PuzzleError> {
+    groups.iter().map(|group| group.common_item_between_rucksacks().map(priority)).sum()
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input<'i> = Vec<Group>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+fn priority(c: char) -> usize {
+    if c.is_ascii_lowercase() {
+        let a = 'a' as usize;
+        return c as usize - a + 1;
+    }
+    if c.is_ascii_uppercase() {
+        let a = 'A' as usize;
+        return c as usize - a + 27;
+    }
+    panic!()
+}
+
+#[derive(Debug)]
+struct Rucksack {
+    compartment_1: Vec<char>,
+    compartment_2: Vec<char>,
+}
+impl Rucksack {
+    fn common_item_between_compartments(&self) -> Result<char, PuzzleError> {
+        let compartment_1_set = self.compartment_1.iter().copied().collect::<BTreeSet<char>>();
+
+        let mut intersection = self
+            .compartment_2
+            .iter()
+            .copied()
+            .filter(|item| compartment_1_set.contains(item))
+            .collect::<BTreeSet<char>>()
+            .into_iter();
+
+        match (intersection.next(), intersection.next()) {
+            (Some(common_item), None) => Ok(common_item),
+            _ => Err(PuzzleError::invalid("compartments must have exactly one item in common")),
+        }
+    }
+}
+
+pub struct Group {
+    rucksacks: [Rucksack; 3],
+}
+impl Group {
+    fn common_item_between_rucksacks(&self) -> Result<char, PuzzleError> {
+        let common_items = self
+            .rucksacks
+            .iter()
+            .map(|r| {
+                let mut set = BTreeSet::new();
+                set.extend(&r.compartment_1);
+                set.extend(&r.compartment_2);
+                set
+            })
+            .fold::<Option<BTreeSet<char>>, _>(None, |intersection, set| {
+                if let Some(intersection) = intersection {
+                    Some(intersection.intersection(&set).cloned().collect())
+                } else {
+                    Some(set)
+                }
+            })
+            .unwrap();
+
+        match common_items.len() {
+            1 => Ok(common_items.into_iter().next().unwrap()),
+            _ => Err(PuzzleError::invalid("rucksacks of a group must have exactly one item in common")),
+        }
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Group>, PuzzleError> {
+        let main_parser = parse_groups;
+        parse_all(s, main_parser)
+    }
+
+    fn parse_groups(s: &str) -> IResult<&str, Vec<Group>> {
+        many0(parse_group)(s)
+    }
+
+    fn parse_group(s: &str) -> IResult<&str, Group> {
+        let (s, rucksacks) = count(parse_rucksack, 3)(s)?;
+        let group = Group {
+            rucksacks: rucksacks.try_into().unwrap(),
+        };
+        Ok((s, group))
+    }
+
+    fn parse_rucksack(s: &str) -> IResult<&str, Rucksack> {
+        let (s, chars) = verify(alpha1, |s: &str| {
+            // is even:
+            s.len() & 1 == 0
+        })(s)?;
+        let (s, _) = line_ending(s)?;
+
+        let compartment_1 = &chars[0..chars.len() / 2];
+        let compartment_2 = &chars[chars.len() / 2..];
+        debug_assert_eq!(compartment_1.len(), compartment_2.len());
+
+        let rucksack = Rucksack {
+            compartment_1: compartment_1.chars().collect(),
+            compartment_2: compartment_2.chars().collect(),
+        };
+
+        Ok((s, rucksack))
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` groups of three rucksacks, at least one.
+    ///
+    /// Every item type other than the badge of a group is in only one of its rucksacks, and only one of those is in
+    /// both compartments of the rucksack.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+            rng.shuffle(&mut items);
+            let (badge, others) = items.split_last().unwrap();
+            for pool in others.chunks(others.len() / 3) {
+                let half: usize = rng.range(2..=12);
+                let mut compartment_1 = vec![pool[0], *badge];
+                let mut compartment_2 = vec![pool[0]];
+                compartment_1.extend((2..half).map(|_| *rng.choose(&pool[1..9])));
+                compartment_2.extend((1..half).map(|_| *rng.choose(&pool[9..])));
+                rng.shuffle(&mut compartment_1);
+                rng.shuffle(&mut compartment_2);
+                input.extend(compartment_1.into_iter().chain(compartment_2));
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+static EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("157"),
+    part_2: Some("70"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 157);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 70);
+}
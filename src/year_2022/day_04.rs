@@ -0,0 +1,136 @@
+//! Day 4: Camp Cleanup
+//!
+//! https://adventofcode.com/2022/day/4
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::ops::RangeInclusive;
+
+pub fn part_1(range_pairs: &[[RangeInclusive<u64>; 2]]) -> Result<usize, PuzzleError> {
+    Ok(range_pairs
+        .iter()
+        .filter(|[range_1, range_2]| range_1.fully_contains(range_2) || range_2.fully_contains(range_1))
+        .count())
+}
+
+pub fn part_2(range_pairs: &[[RangeInclusive<u64>; 2]]) -> Result<usize, PuzzleError> {
+    Ok(range_pairs.iter().filter(|[range_1, range_2]| range_1.overlap_at_all(range_2)).count())
+}
+
+pub struct Day04;
+impl Solution for Day04 {
+    type Input<'i> = Vec<[RangeInclusive<u64>; 2]>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+trait RangeFullyContains {
+    fn fully_contains(&self, other: &Self) -> bool;
+    fn overlap_at_all(&self, other: &Self) -> bool;
+}
+impl<T> RangeFullyContains for RangeInclusive<T>
+where
+    T: PartialOrd,
+{
+    fn fully_contains(&self, other: &Self) -> bool {
+        self.contains(other.start()) && self.contains(other.end())
+    }
+
+    fn overlap_at_all(&self, other: &Self) -> bool {
+        self.contains(other.start()) || self.contains(other.end()) || other.fully_contains(self)
+    }
+}
+
+pub mod parser {
+    use crate::error::PuzzleError;
+    use crate::nom_complete::*;
+    use std::ops::RangeInclusive;
+
+    pub fn parse(s: &str) -> Result<Vec<[RangeInclusive<u64>; 2]>, PuzzleError> {
+        let main_parser = many0(terminated(parse_range_pair, line_ending));
+        parse_all(s, main_parser)
+    }
+
+    fn parse_range_pair(s: &str) -> IResult<&str, [RangeInclusive<u64>; 2]> {
+        let (s, (range_1, range_2)) = separated_pair(parse_range, char(','), parse_range)(s)?;
+        Ok((s, [range_1, range_2]))
+    }
+
+    fn parse_range(s: &str) -> IResult<&str, RangeInclusive<u64>> {
+        let (s, (from, to)) = separated_pair(u64, char('-'), u64)(s)?;
+        Ok((s, from..=to))
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` pairs of section assignments.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let start: u64 = rng.range(1..=99);
+            format!("{start}-{}", rng.range(start..=99))
+        };
+        (0..size).map(|_| format!("{},{}\n", range(), range())).collect()
+    }
+}
+
+static EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("2"),
+    part_2: Some("4"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 2);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 4);
+}
@@ -0,0 +1,306 @@
+//! Day 5: Supply Stacks
+//!
+//! https://adventofcode.com/2022/day/5
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::fmt::Display;
+
+pub fn part_1(input: &Input) -> Result<String, PuzzleError> {
+    let mut stacks = input.stacks.clone();
+
+    for ins in &input.move_instructions {
+        for _ in 0..ins.count {
+            let item = stack(&mut stacks, ins.from)?
+                .pop()
+                .ok_or_else(|| PuzzleError::invalid(format!("cannot move a crate from empty stack {}", ins.from)))?;
+            stack(&mut stacks, ins.to)?.push(item);
+        }
+    }
+    top_crates(&stacks)
+}
+
+pub fn part_2(input: &Input) -> Result<String, PuzzleError> {
+    let mut stacks = input.stacks.clone();
+
+    for ins in &input.move_instructions {
+        let v = stack(&mut stacks, ins.from)?;
+        let Some(at) = v.len().checked_sub(ins.count) else {
+            return Err(PuzzleError::invalid(format!("cannot move {} crates from stack {}", ins.count, ins.from)));
+        };
+        let to_move = v.split_off(at);
+        stack(&mut stacks, ins.to)?.extend(to_move);
+    }
+
+    top_crates(&stacks)
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input<'i> = Input;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input)
+    }
+}
+
+/// Stack by its number, starting from 1.
+fn stack(stacks: &mut [Vec<char>], number: usize) -> Result<&mut Vec<char>, PuzzleError> {
+    number
+        .checked_sub(1)
+        .and_then(|idx| stacks.get_mut(idx))
+        .ok_or_else(|| PuzzleError::invalid(format!("there is no stack {number}")))
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> Result<String, PuzzleError> {
+    stacks
+        .iter()
+        .map(|s| s.last().copied().ok_or_else(|| PuzzleError::unsolvable("a stack ends up empty")))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Input {
+    stacks: Vec<Vec<char>>,
+    move_instructions: Vec<MoveInstruction>,
+}
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The drawing has at least one line, even if every stack is empty.
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        for level in (0..height).rev() {
+            let crates: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(item) => format!("[{item}]"),
+                    None => String::from("   "),
+                })
+                .collect();
+            writeln!(f, "{}", crates.join(" "))?;
+        }
+        let numbers: Vec<String> = (1..=self.stacks.len()).map(|number| number.to_string()).collect();
+        writeln!(f, " {}", numbers.join("   "))?;
+        writeln!(f)?;
+        for ins in &self.move_instructions {
+            writeln!(f, "{ins}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct MoveInstruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+impl Display for MoveInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Input, PuzzleError> {
+        parse_all(s, main_parser)
+    }
+
+    fn main_parser(s: &str) -> IResult<&str, Input> {
+        let (s, stack_lines) = many1(parse_stack_line)(s)?;
+        let (rest, stack_numbers) = parse_stack_number_line(s)?;
+        if stack_lines.iter().map(|s| s.len()).max() != Some(stack_numbers.len()) {
+            // Crates must be stacked on the numbered stacks.
+            return Err(nom::Err::Failure(ParseError::new(s, Expected::Kind(nom::error::ErrorKind::Verify))));
+        }
+        let (s, _) = line_ending(rest)?;
+        let (s, move_instructions) = many1(parse_move_instruction_line)(s)?;
+
+        let mut stacks: Vec<Vec<char>> = vec![Vec::new(); stack_numbers.len()];
+        for stack_line in stack_lines.into_iter().rev() {
+            for (stack, item) in stacks.iter_mut().zip(stack_line) {
+                if let Some(item) = item {
+                    stack.push(item);
+                }
+            }
+        }
+
+        let input = Input { stacks, move_instructions };
+
+        Ok((s, input))
+    }
+
+    fn parse_stack_line(s: &str) -> IResult<&str, Vec<Option<char>>> {
+        let capital_letter = satisfy(|c| {
+            let range = ('A' as usize)..=('Z' as usize);
+            range.contains(&(c as usize))
+        });
+        let something = map(delimited(char('['), capital_letter, char(']')), Some);
+        let nothing = map(tag("   "), |_| None);
+        let something_or_nothing = alt((something, nothing));
+        terminated(separated_list0(char(' '), something_or_nothing), line_ending)(s)
+    }
+    #[test]
+    fn test_parse_stack_line() {
+        assert_eq!(parse_stack_line("    [D]\n").unwrap().1, vec![None, Some('D')]);
+        assert_eq!(parse_stack_line("[N] [C]\n").unwrap().1, vec![Some('N'), Some('C')]);
+    }
+
+    fn parse_stack_number_line(s: &str) -> IResult<&str, Vec<u32>> {
+        let stack_numbers = preceded(
+            char(' '),
+            verify(separated_list1(space1, u32), |list: &Vec<u32>| {
+                list.iter().copied().eq(1..=(list.len() as u32))
+            }),
+        );
+        terminated(stack_numbers, line_ending)(s)
+    }
+    #[test]
+    fn test_parse_stack_number_line() {
+        parse_stack_number_line(" 1   2   3\n").unwrap();
+    }
+
+    fn parse_move_instruction_line(s: &str) -> IResult<&str, MoveInstruction> {
+        let (s, _) = tag("move")(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, count) = u32(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, _) = tag("from")(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, from) = u32(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, _) = tag("to")(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, to) = u32(s)?;
+        let (s, _) = line_ending(s)?;
+
+        let move_instruction = MoveInstruction {
+            count: count as usize,
+            from: from as usize,
+            to: to as usize,
+        };
+
+        Ok((s, move_instruction))
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random drawing of stacks of crates, followed by `size` moves, at least one.
+    ///
+    /// No move takes the last crate off a stack, so that every stack ends up with a crate on top.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let n_stacks = rng.range(2..=9);
+        let mut heights: Vec<usize> = (0..n_stacks).map(|_| rng.range(1..=8)).collect();
+        // There are more crates than stacks, so that there is always a stack to move crates from.
+        heights[0] = heights[0].max(2);
+
+        let mut input = String::new();
+        for level in (0..*heights.iter().max().unwrap()).rev() {
+            let crates: Vec<String> = heights
+                .iter()
+                .map(|&height| {
+                    if height > level {
+                        format!("[{}]", rng.range(b'A'..=b'Z') as char)
+                    } else {
+                        String::from("   ")
+                    }
+                })
+                .collect();
+            input += &crates.join(" ");
+            input.push('\n');
+        }
+        let numbers: Vec<String> = (1..=n_stacks).map(|number| number.to_string()).collect();
+        input += &format!(" {}\n\n", numbers.join("   "));
+
+        for _ in 0..size.max(1) {
+            let from = loop {
+                let from = rng.index(n_stacks);
+                if heights[from] > 1 {
+                    break from;
+                }
+            };
+            let to = loop {
+                let to = rng.index(n_stacks);
+                if to != from {
+                    break to;
+                }
+            };
+            let count = rng.range(1..=heights[from] - 1);
+            heights[from] -= count;
+            heights[to] += count;
+            input += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+        }
+        input
+    }
+}
+
+static EXAMPLE: &str = r#"
+    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("CMZ"),
+    part_2: Some("MCD"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), "CMZ");
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), "MCD");
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(0..20, |rng| generator::generate(rng, 10), parser::parse, Input::to_string);
+}
@@ -0,0 +1,174 @@
+//! Day 6: Tuning Trouble
+//!
+//! https://adventofcode.com/2022/day/6
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+pub fn part_1(input: &str) -> Result<usize, PuzzleError> {
+    find_unique_window(input, 4)
+}
+
+pub fn part_2(input: &str) -> Result<usize, PuzzleError> {
+    find_unique_window(input, 14)
+}
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input<'i> = &'i str;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        Ok(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+fn find_unique_window(input: &str, window_size: usize) -> Result<usize, PuzzleError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut iter_front = chars.iter();
+    let iter_tail = chars.iter();
+
+    let mut window: HashMap<char, usize> = HashMap::new();
+
+    for _ in 0..window_size {
+        let Some(c) = iter_front.next() else {
+            return Err(PuzzleError::invalid(format!("input is shorter than {window_size} characters")));
+        };
+        *window.entry(*c).or_default() += 1;
+    }
+    if window.len() == window_size {
+        return Ok(window_size);
+    }
+
+    for (front, (iter_tail_idx, tail)) in iter_front.zip(iter_tail.enumerate()) {
+        match window.entry(*tail) {
+            Entry::Vacant(_) => {
+                unreachable!()
+            }
+            Entry::Occupied(mut e) => {
+                if *e.get() == 1 {
+                    e.remove();
+                } else {
+                    *e.get_mut() -= 1;
+                }
+            }
+        }
+        *window.entry(*front).or_default() += 1;
+
+        if window.len() == window_size {
+            return Ok(window_size + (iter_tail_idx + 1));
+        }
+    }
+
+    Err(PuzzleError::unsolvable(format!(
+        "no {window_size} consecutive characters are all different"
+    )))
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random datastream of `size` characters from only four letters, followed by a start-of-message marker.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut letters);
+        let mut input: String = (0..size).map(|_| *rng.choose(&letters[..4])).collect();
+        input.extend(&letters[..14]);
+        input.push('\n');
+        input
+    }
+}
+
+static EXAMPLES: &[Example] = &[
+    Example {
+        name: "example_1",
+        input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+        part_1: Some("7"),
+        part_2: Some("19"),
+        params: &[],
+    },
+    Example {
+        name: "example_2",
+        input: "bvwbjplbgvbhsrlpgdmjqwftvncz",
+        part_1: Some("5"),
+        part_2: Some("23"),
+        params: &[],
+    },
+    Example {
+        name: "example_3",
+        input: "nppdvjthqldpwncqszvftbrmjlhg",
+        part_1: Some("6"),
+        part_2: Some("23"),
+        params: &[],
+    },
+    Example {
+        name: "example_4",
+        input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+        part_1: Some("10"),
+        part_2: Some("29"),
+        params: &[],
+    },
+    Example {
+        name: "example_5",
+        input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+        part_1: Some("11"),
+        part_2: Some("26"),
+        params: &[],
+    },
+];
+
+#[test]
+fn part_1_examples() {
+    assert_eq!(part_1("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(), 7);
+    assert_eq!(part_1("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap(), 5);
+    assert_eq!(part_1("nppdvjthqldpwncqszvftbrmjlhg").unwrap(), 6);
+    assert_eq!(part_1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap(), 10);
+    assert_eq!(part_1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap(), 11);
+}
+
+#[test]
+fn part_2_examples() {
+    assert_eq!(part_2("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(), 19);
+    assert_eq!(part_2("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap(), 23);
+    assert_eq!(part_2("nppdvjthqldpwncqszvftbrmjlhg").unwrap(), 23);
+    assert_eq!(part_2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap(), 29);
+    assert_eq!(part_2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap(), 26);
+}
+
+#[test]
+fn part_1_no_marker() {
+    assert!(matches!(part_1("abc"), Err(PuzzleError::Invalid(_))));
+    assert!(matches!(part_1("abcabcabc"), Err(PuzzleError::Unsolvable(_))));
+}
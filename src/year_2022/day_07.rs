@@ -0,0 +1,465 @@
+//! Day 7: No space left on device
+//!
+//! https://adventofcode.com/2022/day/7
+
+use crate::error::PuzzleError;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use file_system::FileSystem;
+use file_system::FileSystemNode;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub fn part_1(commands: &[Command]) -> Result<u64, PuzzleError> {
+    let file_system = build_file_system(commands)?;
+
+    let dir_sizes = dir_sizes(&file_system)?;
+    Ok(dir_sizes.into_iter().filter(|dir_size| *dir_size <= 100_000).sum())
+}
+
+pub fn part_2(commands: &[Command], params: &Params) -> Result<u64, PuzzleError> {
+    let file_system = build_file_system(commands)?;
+
+    let used_space = file_system.size_recursive(Path::new(""))?;
+    let free_space = params
+        .total_space
+        .checked_sub(used_space)
+        .ok_or_else(|| PuzzleError::invalid("files take up more than the total space"))?;
+    let need_to_free = params
+        .unused_space_required
+        .checked_sub(free_space)
+        .ok_or_else(|| PuzzleError::unsolvable("enough space is already unused"))?;
+
+    let dir_sizes = dir_sizes(&file_system)?;
+    dir_sizes
+        .into_iter()
+        .filter(|dir_size| *dir_size >= need_to_free)
+        .min()
+        .ok_or_else(|| PuzzleError::unsolvable("no directory is large enough to free up the space"))
+}
+
+pub struct Day07;
+impl Solution for Day07 {
+    type Input<'i> = Vec<Command>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "No space left on device"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+/// Disk space of the device, which is the same for the example and the real input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Total disk space.
+    pub total_space: u64,
+
+    /// Unused space needed for the update.
+    pub unused_space_required: u64,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            total_space: 70_000_000,
+            unused_space_required: 30_000_000,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["total_space", "unused_space_required"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "total_space" => self.total_space = params::parse(name, value)?,
+            "unused_space_required" => self.unused_space_required = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+/// Size of every directory in the file system.
+fn dir_sizes(file_system: &FileSystem) -> Result<Vec<u64>, PuzzleError> {
+    file_system.dirs().map(|(path, _)| file_system.size_recursive(path)).collect()
+}
+
+fn build_file_system(commands: &[Command]) -> Result<FileSystem, PuzzleError> {
+    let mut file_system = FileSystem::default();
+    let mut cwd = PathBuf::new();
+
+    for command in commands {
+        match command {
+            Command::Cd(cd) => match cd {
+                Cd::Root => {
+                    cwd.clear();
+                }
+                Cd::In(dir_name) => {
+                    cwd.push(dir_name);
+                }
+                Cd::Out => {
+                    if !cwd.pop() {
+                        return Err(PuzzleError::invalid("cannot cd out of the root directory"));
+                    }
+                }
+            },
+            Command::Ls(ls) => {
+                let entries = ls.entries.iter().map(LsEntry::name).map(ToOwned::to_owned).collect();
+                file_system.insert(cwd.clone(), FileSystemNode::Directory { entries: Some(entries) });
+
+                for entry in &ls.entries {
+                    match entry {
+                        LsEntry::Directory(d) => {
+                            // Keep what is known about the directory if it was listed before.
+                            let p = cwd.join(&d.name);
+                            file_system.insert_if_absent(p, FileSystemNode::Directory { entries: None });
+                        }
+                        LsEntry::File(f) => {
+                            let p = cwd.join(&f.name);
+                            file_system.insert(p, FileSystemNode::File { size: f.size });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(file_system)
+}
+
+pub enum Command {
+    Cd(Cd),
+    Ls(Ls),
+}
+
+pub enum Cd {
+    Root,
+    In(String),
+    Out,
+}
+
+pub struct Ls {
+    entries: Vec<LsEntry>,
+}
+
+#[derive(Debug)]
+enum LsEntry {
+    Directory(LsEntryDirectory),
+    File(LsEntryFile),
+}
+impl LsEntry {
+    fn name(&self) -> &str {
+        match self {
+            LsEntry::Directory(d) => &d.name,
+            LsEntry::File(f) => &f.name,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct LsEntryDirectory {
+    name: String,
+}
+
+#[derive(Debug)]
+struct LsEntryFile {
+    name: String,
+    size: u64,
+}
+
+mod file_system {
+    use crate::error::PuzzleError;
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    #[derive(Default, Debug)]
+    pub(super) struct FileSystem {
+        nodes: BTreeMap<PathBuf, FileSystemNode>,
+    }
+    impl FileSystem {
+        /// Return iterator over directories found in file system.
+        pub fn dirs(&self) -> impl Iterator<Item = (&PathBuf, &FileSystemNode)> {
+            self.nodes.iter().filter(|(_, node)| node.is_dir())
+        }
+
+        /// Insert path into file system.
+        pub fn insert(&mut self, path: PathBuf, node: FileSystemNode) -> Option<FileSystemNode> {
+            self.nodes.insert(path, node)
+        }
+
+        /// Insert path into file system, unless the path is already known.
+        pub fn insert_if_absent(&mut self, path: PathBuf, node: FileSystemNode) {
+            self.nodes.entry(path).or_insert(node);
+        }
+
+        /// Size of file if node is a file, size of all files in subtree if node is a dir.
+        ///
+        /// Fails if any path during traversal is not found in filesystem, or is a dir that was never listed.
+        pub fn size_recursive(&self, path: &Path) -> Result<u64, PuzzleError> {
+            self.size_within(path, 0)
+        }
+
+        /// Size of a node `depth` directories below where the traversal started.
+        ///
+        /// Fails if the traversal goes deeper than there are nodes, which means that a directory is inside itself.
+        fn size_within(&self, path: &Path, depth: usize) -> Result<u64, PuzzleError> {
+            if depth > self.nodes.len() {
+                return Err(PuzzleError::invalid(format!("path {path:?} is inside itself")));
+            }
+            let node = self
+                .nodes
+                .get(path)
+                .ok_or_else(|| PuzzleError::invalid(format!("path {path:?} not found in file system")))?;
+
+            match node {
+                FileSystemNode::Directory { entries } => entries
+                    .as_ref()
+                    .ok_or_else(|| PuzzleError::invalid(format!("path {path:?} has no information on its entries")))?
+                    .iter()
+                    .map(|entry| self.size_within(&path.join(entry), depth + 1))
+                    .sum(),
+                FileSystemNode::File { size } => Ok(*size),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub(super) enum FileSystemNode {
+        Directory {
+            /// Entries is None until we have entered with cd and listed the contents with ls
+            entries: Option<Vec<String>>,
+        },
+        File {
+            size: u64,
+        },
+    }
+    impl FileSystemNode {
+        pub fn is_dir(&self) -> bool {
+            matches!(self, FileSystemNode::Directory { .. })
+        }
+
+        #[allow(dead_code)]
+        pub fn is_file(&self) -> bool {
+            matches!(self, FileSystemNode::File { .. })
+        }
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Command>, PuzzleError> {
+        parse_all(s, many0(parse_command))
+    }
+
+    fn parse_command(s: &str) -> IResult<&str, Command> {
+        let parse_cd = map(parse_cd, Command::Cd);
+        let parse_ls = map(parse_ls, Command::Ls);
+        alt((parse_cd, parse_ls))(s)
+    }
+
+    fn parse_cd(s: &str) -> IResult<&str, Cd> {
+        let (s, _) = tag("$ cd ")(s)?;
+
+        let root = map(tag("/"), |_| Cd::Root);
+        let cd_in = map(alphanumeric1, |p: &str| Cd::In(p.into()));
+        let cd_out = map(tag(".."), |_| Cd::Out);
+
+        let (s, cd) = terminated(alt((root, cd_in, cd_out)), line_ending)(s)?;
+        Ok((s, cd))
+    }
+
+    fn parse_ls(s: &str) -> IResult<&str, Ls> {
+        let (s, _) = terminated(tag("$ ls"), line_ending)(s)?;
+
+        let parse_dir = map(parse_ls_dir, LsEntry::Directory);
+        let parse_file = map(parse_ls_file, LsEntry::File);
+
+        let (s, entries) = many0(alt((parse_dir, parse_file)))(s)?;
+
+        Ok((s, Ls { entries }))
+    }
+
+    fn parse_ls_dir(s: &str) -> IResult<&str, LsEntryDirectory> {
+        let (s, _) = tag("dir ")(s)?;
+        let (s, name) = terminated(parse_name, line_ending)(s)?;
+        Ok((s, LsEntryDirectory { name: name.to_owned() }))
+    }
+    #[test]
+    fn test_parse_ls_dir() {
+        assert_eq!(parse_ls_dir("dir d\n").unwrap().1, LsEntryDirectory { name: String::from("d") });
+        assert!(parse_ls_dir("dir .\n").is_err());
+        assert!(parse_ls_dir("dir ..\n").is_err());
+        assert!(parse_ls_dir("dir a/b\n").is_err());
+    }
+
+    fn parse_ls_file(s: &str) -> IResult<&str, LsEntryFile> {
+        let (s, size) = u64(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, name) = terminated(parse_name, line_ending)(s)?;
+        let file = LsEntryFile { name: name.to_owned(), size };
+        Ok((s, file))
+    }
+
+    /// Name of an entry in a directory, which cannot refer to another directory, so that the file system is a tree.
+    fn parse_name(s: &str) -> IResult<&str, &str> {
+        verify(take_till1(char::is_whitespace), |name: &str| name != "." && name != ".." && !name.contains('/'))(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random terminal output that lists every directory of a file system with `size` files, at least one.
+    ///
+    /// The files take up between 45 and 65 million in total, so that part 2 has space to free up.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let n_files = size.max(1);
+        let n_dirs = n_files / 4 + 1;
+
+        // Every directory but the root is in a directory that comes before it.
+        let mut tree = Tree {
+            names: (0..n_dirs).map(|dir| format!("{}{dir}", name(rng))).collect(),
+            dirs: vec![Vec::new(); n_dirs],
+            files: vec![Vec::new(); n_dirs],
+        };
+        for dir in 1..n_dirs {
+            tree.dirs[rng.index(dir)].push(dir);
+        }
+
+        let total_size: u64 = rng.range(45_000_000..=65_000_000);
+        let weights: Vec<u64> = (0..n_files).map(|_| rng.range(1..=1000)).collect();
+        let total_weight: u64 = weights.iter().sum();
+        for (file, weight) in weights.into_iter().enumerate() {
+            // Extensions keep the names of files apart from those of directories.
+            let name = format!("{}{file}.{}", name(rng), rng.choose(&["txt", "dat", "log", "lst", "ext"]));
+            tree.files[rng.index(n_dirs)].push((name, (weight * total_size / total_weight).max(1)));
+        }
+
+        let mut input = String::from("$ cd /\n");
+        tree.list(rng, 0, &mut input);
+        input
+    }
+
+    struct Tree {
+        names: Vec<String>,
+
+        /// Directories in each directory.
+        dirs: Vec<Vec<usize>>,
+
+        /// Names and sizes of the files in each directory.
+        files: Vec<Vec<(String, u64)>>,
+    }
+    impl Tree {
+        /// List a directory, and then every directory in it.
+        fn list(&self, rng: &mut Rng, dir: usize, input: &mut String) {
+            input.push_str("$ ls\n");
+            let mut entries: Vec<String> = self.dirs[dir]
+                .iter()
+                .map(|&other| format!("dir {}\n", self.names[other]))
+                .chain(self.files[dir].iter().map(|(name, size)| format!("{size} {name}\n")))
+                .collect();
+            rng.shuffle(&mut entries);
+            input.extend(entries);
+
+            for &other in &self.dirs[dir] {
+                *input += &format!("$ cd {}\n", self.names[other]);
+                self.list(rng, other, input);
+                input.push_str("$ cd ..\n");
+            }
+        }
+    }
+
+    fn name(rng: &mut Rng) -> String {
+        (0..rng.range(1..=6)).map(|_| rng.range(b'a'..=b'z') as char).collect()
+    }
+}
+
+static EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("95437"),
+    part_2: Some("24933642"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 95437);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 24933642);
+}
+
+#[test]
+fn parts_fail_if_a_directory_is_inside_itself() {
+    let dir = |name: &str| LsEntry::Directory(LsEntryDirectory { name: name.to_owned() });
+    let commands = [
+        Command::Cd(Cd::Root),
+        Command::Ls(Ls { entries: vec![dir("a")] }),
+        Command::Cd(Cd::In(String::from("a"))),
+        Command::Ls(Ls { entries: vec![dir(".")] }),
+    ];
+    assert!(part_1(&commands).is_err());
+    assert!(part_2(&commands, &Params::default()).is_err());
+}
@@ -2,31 +2,71 @@
 //!
 //! https://adventofcode.com/2022/day/8
 
-pub fn part_1(input: &str) -> usize {
-    let patch_of_tall_trees = parser::parse(input);
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 
+pub fn part_1(patch_of_tall_trees: &PatchOfTallTrees) -> Result<usize, PuzzleError> {
     let tree_iter = patch_of_tall_trees
         .iter()
         .enumerate()
         .flat_map(|(row_idx, row)| row.iter().enumerate().map(move |(col_idx, tree)| (row_idx, col_idx, tree)));
 
-    tree_iter
-        .filter(|(row_idx, col_idx, _tree)| is_visible(&patch_of_tall_trees, *row_idx, *col_idx))
-        .count()
+    Ok(tree_iter
+        .filter(|(row_idx, col_idx, _tree)| is_visible(patch_of_tall_trees, *row_idx, *col_idx))
+        .count())
 }
 
-pub fn part_2(input: &str) -> usize {
-    let patch_of_tall_trees = parser::parse(input);
-
+pub fn part_2(patch_of_tall_trees: &PatchOfTallTrees) -> Result<usize, PuzzleError> {
     let tree_iter = patch_of_tall_trees
         .iter()
         .enumerate()
         .flat_map(|(row_idx, row)| row.iter().enumerate().map(move |(col_idx, tree)| (row_idx, col_idx, tree)));
 
-    tree_iter
-        .map(|(row_idx, col_idx, _tree)| scenic_score(&patch_of_tall_trees, row_idx, col_idx))
+    // The parser guarantees there is at least one tree.
+    Ok(tree_iter
+        .map(|(row_idx, col_idx, _tree)| scenic_score(patch_of_tall_trees, row_idx, col_idx))
         .max()
-        .unwrap()
+        .unwrap())
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    type Input<'i> = PatchOfTallTrees;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
 }
 
 fn is_visible(patch_of_tall_trees: &PatchOfTallTrees, row_idx: usize, col_idx: usize) -> bool {
@@ -132,22 +172,34 @@ fn scenic_score(patch_of_tall_trees: &PatchOfTallTrees, row_idx: usize, col_idx:
 
 type PatchOfTallTrees = Vec<Vec<u8>>;
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> PatchOfTallTrees {
-        all_consuming(main_parser)(s).unwrap().1
+    pub fn parse(s: &str) -> Result<PatchOfTallTrees, PuzzleError> {
+        parse_all(s, main_parser)
     }
 
     fn main_parser(s: &str) -> IResult<&str, PatchOfTallTrees> {
         let digit = map(satisfy(|c| c.is_ascii_digit()), |c| c.to_digit(10).unwrap() as u8);
         let parse_line = terminated(many1(digit), line_ending);
-        many1(parse_line)(s)
+        let is_rectangular = |rows: &PatchOfTallTrees| rows.iter().all(|row| row.len() == rows[0].len());
+        verify(many1(parse_line), is_rectangular)(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random square patch of trees, `size` trees wide and at least one.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| (0..side).map(|_| char::from(b'0' + rng.range(0..=9u8))).chain(['\n']).collect::<String>())
+            .collect()
     }
 }
 
-#[cfg(test)]
 static EXAMPLE: &str = "\
 30373
 25512
@@ -156,12 +208,20 @@ static EXAMPLE: &str = "\
 35390
 ";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("21"),
+    part_2: Some("8"),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 21);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 21);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE), 8);
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 8);
 }
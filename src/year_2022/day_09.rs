@@ -2,33 +2,75 @@
 //!
 //! https://adventofcode.com/2022/day/9
 
+use crate::cancel;
+use crate::error::PuzzleError;
 use crate::lib::vector_2d::Vector2D;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 use std::collections::HashSet;
 
-pub fn part_1(input: &str) -> usize {
-    let moves = parser::parse(input);
+pub fn part_1(moves: &[Move]) -> Result<usize, PuzzleError> {
     let rope = Rope::new(2);
     count_positions_visited_by_tail(rope, moves)
 }
 
-pub fn part_2(input: &str) -> usize {
-    let moves = parser::parse(input);
+pub fn part_2(moves: &[Move]) -> Result<usize, PuzzleError> {
     let rope = Rope::new(10);
     count_positions_visited_by_tail(rope, moves)
 }
 
-fn count_positions_visited_by_tail(mut rope: Rope, moves: Vec<Move>) -> usize {
+pub struct Day09;
+impl Solution for Day09 {
+    type Input<'i> = Vec<Move>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+fn count_positions_visited_by_tail(mut rope: Rope, moves: &[Move]) -> Result<usize, PuzzleError> {
     let mut tail_visited = HashSet::<Vector2D<i32>>::new();
     tail_visited.insert(rope.tail());
 
-    for m in moves {
+    for &m in moves {
         for _ in 0..m.count {
+            cancel::check()?;
             rope.move_rope(m);
             tail_visited.insert(rope.tail());
         }
     }
 
-    tail_visited.len()
+    Ok(tail_visited.len())
 }
 
 #[derive(Debug)]
@@ -71,7 +113,7 @@ impl Rope {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Move {
+pub struct Move {
     direction: Direction,
     count: u32,
 }
@@ -84,12 +126,12 @@ enum Direction {
     Down,
 }
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<Move> {
-        all_consuming(many0(main_parser))(s).unwrap().1
+    pub fn parse(s: &str) -> Result<Vec<Move>, PuzzleError> {
+        parse_all(s, many0(main_parser))
     }
 
     fn main_parser(s: &str) -> IResult<&str, Move> {
@@ -106,7 +148,17 @@ mod parser {
     }
 }
 
-#[cfg(test)]
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` moves of the head of the rope.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", rng.choose(&['R', 'L', 'U', 'D']), rng.range(1..=20)))
+            .collect()
+    }
+}
+
 static EXAMPLE: &str = "\
 R 4
 U 4
@@ -118,7 +170,6 @@ L 5
 R 2
 ";
 
-#[cfg(test)]
 static EXAMPLE_PART_2: &str = "\
 R 5
 U 8
@@ -130,12 +181,29 @@ L 25
 U 20
 ";
 
+static EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part_1: Some("13"),
+        part_2: Some("1"),
+        params: &[],
+    },
+    Example {
+        name: "larger_example",
+        input: EXAMPLE_PART_2,
+        part_1: None,
+        part_2: Some("36"),
+        params: &[],
+    },
+];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 13);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 13);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE_PART_2), 36);
+    assert_eq!(part_2(&parser::parse(EXAMPLE_PART_2).unwrap()).unwrap(), 36);
 }
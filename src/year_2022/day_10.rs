@@ -2,28 +2,30 @@
 //!
 //! https://adventofcode.com/2022/day/10
 
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 use std::fmt::Display;
 
-pub fn part_1(input: &str) -> i64 {
-    let instructions = parser::parse(input);
+pub fn part_1(instructions: &[Instruction]) -> Result<i64, PuzzleError> {
     let mut cpu = CpuEmulator::default();
-    instructions
-        .into_iter()
-        .flat_map(|ins| cpu.feed(ins))
+    Ok(instructions
+        .iter()
+        .flat_map(|&ins| cpu.feed(ins))
         .enumerate()
         .map(|(cycle, tick)| (cycle + 1, tick))
         .skip(19)
         .step_by(40)
         .map(|(cycle, tick)| signal_strength(cycle, tick.state_before.register))
-        .sum()
+        .sum())
 }
 
-pub fn part_2(input: &str) -> CrtImage {
-    let instructions = parser::parse(input);
+pub fn part_2(instructions: &[Instruction]) -> Result<CrtImage, PuzzleError> {
     let mut cpu = CpuEmulator::default();
-    instructions
-        .into_iter()
-        .flat_map(|ins| cpu.feed(ins))
+    Ok(instructions
+        .iter()
+        .flat_map(|&ins| cpu.feed(ins))
         .enumerate()
         .map(|(cycle, tick)| (cycle + 1, tick))
         .map(|(cycle, tick)| {
@@ -31,7 +33,45 @@ pub fn part_2(input: &str) -> CrtImage {
             let lit = col.abs_diff(tick.state_before.register) <= 1;
             if lit { '#' } else { '.' }
         })
-        .collect()
+        .collect())
+}
+
+pub struct Day10;
+impl Solution for Day10 {
+    type Input<'i> = Vec<Instruction>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
 }
 
 fn signal_strength(cycle: usize, register: i64) -> i64 {
@@ -114,17 +154,17 @@ struct Tick {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     Noop,
     Addx(i64),
 }
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<Instruction> {
-        all_consuming(many0(parse_instruction))(s).unwrap().1
+    pub fn parse(s: &str) -> Result<Vec<Instruction>, PuzzleError> {
+        parse_all(s, many0(parse_instruction))
     }
 
     fn parse_instruction(s: &str) -> IResult<&str, Instruction> {
@@ -134,7 +174,30 @@ mod parser {
     }
 }
 
-#[cfg(test)]
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random program of at least `size` instructions, which runs long enough to draw the whole screen and keeps the
+    /// sprite on the screen.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        let (mut instructions, mut cycles, mut x) = (0, 0, 1i64);
+        while instructions < size || cycles < 240 {
+            if rng.chance(1, 3) {
+                input.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let v = rng.range((-x).max(-20)..=(39 - x).min(20));
+                input += &format!("addx {v}\n");
+                x += v;
+                cycles += 2;
+            }
+            instructions += 1;
+        }
+        input
+    }
+}
+
 static EXAMPLE: &str = "\
 addx 15
 addx -11
@@ -284,7 +347,24 @@ noop
 noop
 ";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("13140"),
+    part_2: Some(
+        "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+",
+    ),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 13140);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 13140);
 }
@@ -0,0 +1,632 @@
+//! Day 11: Monkey in the Middle
+//!
+//! https://adventofcode.com/2022/day/11
+
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::ops::Div;
+use std::ops::Mul;
+
+pub fn part_1(monkeys: &[Monkey], params: &Params) -> Result<u64, PuzzleError> {
+    let mut monkeys = monkeys.to_vec();
+    for _round in 1..=params.part_1_rounds {
+        cancel::check()?;
+        for monkey_idx in 0..monkeys.len() {
+            loop {
+                let monkey = &mut monkeys[monkey_idx];
+                let Some(mut item) = monkey.items.pop_front() else {
+                    break;
+                };
+                monkey.inspected_items += 1;
+
+                let rhs = match monkey.expression.operand {
+                    Operand::Value(v) => v,
+                    Operand::Old => item.worry_level.current_value,
+                };
+                let worry_level = match monkey.expression.operation {
+                    Operation::AddAssign => item.worry_level.current_value.checked_add(rhs),
+                    Operation::MulAssign => item.worry_level.current_value.checked_mul(rhs),
+                };
+                item.worry_level.current_value = worry_level.ok_or_else(|| PuzzleError::unsolvable("a worry level gets too large"))?;
+
+                // Relief
+                item.worry_level.current_value /= 3;
+
+                let throw_to = if is_divisible_by(&item.worry_level.current_value, &monkey.test_divisible_by) {
+                    monkey.if_true_throw_to
+                } else {
+                    monkey.if_false_throw_to
+                };
+
+                let throw_to = throw_target(monkeys.len(), monkey_idx, throw_to)?;
+                let other_monkey = &mut monkeys[throw_to];
+                other_monkey.items.push_back(item);
+            }
+        }
+    }
+
+    Ok(monkey_business(monkeys))
+}
+
+pub fn part_2(monkeys: &[Monkey], params: &Params) -> Result<u64, PuzzleError> {
+    let mut monkeys = monkeys.to_vec();
+    let mut dividers: Vec<u64> = monkeys.iter().map(|m| m.test_divisible_by).collect();
+    dividers.sort();
+    dividers.dedup();
+
+    for item in monkeys.iter_mut().flat_map(|m| m.items.iter_mut()) {
+        for divider in dividers.iter().copied() {
+            let remainder_if_divided = item.worry_level.current_value % divider;
+            item.worry_level.remainder_if_divided_by.insert(divider, remainder_if_divided);
+        }
+    }
+
+    for _round in 1..=params.part_2_rounds {
+        cancel::check()?;
+        for monkey_idx in 0..monkeys.len() {
+            loop {
+                let monkey = &mut monkeys[monkey_idx];
+                let Some(mut item) = monkey.items.pop_front() else {
+                    break;
+                };
+                monkey.inspected_items += 1;
+
+                for (divider, remainder) in item.worry_level.remainder_if_divided_by.iter_mut() {
+                    // Wide enough that neither adding nor multiplying overflows.
+                    let (d, r) = (u128::from(*divider), u128::from(*remainder));
+                    let new_remainder = match (monkey.expression.operation, monkey.expression.operand) {
+                        (Operation::AddAssign, Operand::Value(v)) => (r + u128::from(v)) % d,
+                        (Operation::AddAssign, Operand::Old) => {
+                            // Adding old value is the same as multiplying by 2.
+                            (r * 2) % d
+                        }
+                        (Operation::MulAssign, Operand::Value(v)) => (r * u128::from(v)) % d,
+                        (Operation::MulAssign, Operand::Old) => {
+                            // This is the math-heavy case.
+                            //
+                            // w: current worry-level, that is unknown.
+                            // d: divider (given in the input, per monkey).
+                            // r: the remainder of w divided by d, i.e. `x  mod d`. We know this value.
+                            //
+                            // Goal: Find `w^2  mod d`, the new remainder to be stored.
+                            //
+                            // First, note that
+                            //   w = r + n×d
+                            // for some natural n.
+                            // Therefore,
+                            //   w^2                                mod d
+                            //   = (r + n × d)^2                    mod d
+                            //   = r^2 + n^2 × d^2 + 2 × r × n × d  mod d
+                            //   = r^2 + 0         + 0              mod d
+                            //   = r^2                              mod d
+                            let r_squared = r * r;
+                            r_squared % d
+                        }
+                    };
+
+                    *remainder = new_remainder as u64;
+                }
+
+                let test: bool = *item.worry_level.remainder_if_divided_by.get(&monkey.test_divisible_by).unwrap() == 0;
+                let throw_to = if test { monkey.if_true_throw_to } else { monkey.if_false_throw_to };
+
+                let throw_to = throw_target(monkeys.len(), monkey_idx, throw_to)?;
+                let other_monkey = &mut monkeys[throw_to];
+                other_monkey.items.push_back(item);
+            }
+        }
+    }
+
+    Ok(monkey_business(monkeys))
+}
+
+/// Index of the monkey that a monkey throws to, which must be another one of the `n_monkeys` monkeys.
+fn throw_target(n_monkeys: usize, monkey_idx: usize, throw_to: u64) -> Result<usize, PuzzleError> {
+    match usize::try_from(throw_to) {
+        Ok(idx) if idx < n_monkeys && idx != monkey_idx => Ok(idx),
+        _ => Err(PuzzleError::invalid(format!("monkey {monkey_idx} cannot throw to monkey {throw_to}"))),
+    }
+}
+
+/// Problems with the monkeys, which must each throw to two other monkeys.
+pub fn validate(monkeys: &[Monkey]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if monkeys.is_empty() {
+        problems.push(String::from("there are no monkeys"));
+    }
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        if monkey.number != idx as u64 {
+            problems.push(format!("monkey {idx} is numbered {}", monkey.number));
+        }
+        for throw_to in [monkey.if_true_throw_to, monkey.if_false_throw_to] {
+            if throw_to as usize >= monkeys.len() || throw_to as usize == idx {
+                problems.push(format!("monkey {idx} cannot throw to monkey {throw_to}"));
+            }
+        }
+    }
+    problems
+}
+
+pub struct Day11;
+impl Solution for Day11 {
+    type Input<'i> = Vec<Monkey>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input, params).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Number of rounds in part 1.
+    pub part_1_rounds: usize,
+
+    /// Number of rounds in part 2.
+    pub part_2_rounds: usize,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_rounds: 20,
+            part_2_rounds: 10_000,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["part_1_rounds", "part_2_rounds"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part_1_rounds" => self.part_1_rounds = params::parse(name, value)?,
+            "part_2_rounds" => self.part_2_rounds = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+fn monkey_business(mut monkeys: Vec<Monkey>) -> u64 {
+    monkeys.sort_by_key(|m| std::cmp::Reverse(m.inspected_items));
+    monkeys.iter().take(2).map(|m| m.inspected_items).reduce(std::ops::Mul::mul).unwrap()
+}
+
+fn is_divisible_by<'a, T>(value: &'a T, divider: &'a T) -> bool
+where
+    &'a T: Div<&'a T>,
+    <&'a T as Div<&'a T>>::Output: Mul<&'a T>,
+    <<&'a T as Div<&'a T>>::Output as Mul<&'a T>>::Output: PartialEq<T>,
+{
+    let x = value / divider;
+    x * divider == *value
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct WorryLevel {
+    /// Current value.
+    ///
+    /// This is unsed for part 2!
+    current_value: u64,
+
+    /// Map from denominators of potential divisions, and the remainder should that division by carried out.
+    ///
+    /// This is unused for part 1!
+    remainder_if_divided_by: HashMap<u64, u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
+    /// Number of the monkey in the notes.
+    number: u64,
+    items: VecDeque<Item>,
+    inspected_items: u64,
+    expression: ArithmeticExpression,
+    test_divisible_by: u64,
+    if_true_throw_to: u64,
+    if_false_throw_to: u64,
+}
+
+/// Notes on the monkey, with the current worry levels of its items as starting items.
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let items: Vec<String> = self.items.iter().map(|item| item.worry_level.current_value.to_string()).collect();
+        let operator = match self.expression.operation {
+            Operation::AddAssign => '+',
+            Operation::MulAssign => '*',
+        };
+        let operand = match self.expression.operand {
+            Operand::Value(value) => value.to_string(),
+            Operand::Old => String::from("old"),
+        };
+        writeln!(f, "Monkey {}:", self.number)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: new = old {operator} {operand}")?;
+        writeln!(f, "  Test: divisible by {}", self.test_divisible_by)?;
+        writeln!(f, "    If true: throw to monkey {}", self.if_true_throw_to)?;
+        writeln!(f, "    If false: throw to monkey {}", self.if_false_throw_to)
+    }
+}
+
+/// All of them start with new = old, so that part is omitted.
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+struct ArithmeticExpression {
+    operation: Operation,
+    operand: Operand,
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+enum Operation {
+    AddAssign,
+    MulAssign,
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+enum Operand {
+    Value(u64),
+    Old,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Item {
+    worry_level: WorryLevel,
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Monkey>, PuzzleError> {
+        parse_all(s, separated_list0(line_ending, parse_monkey))
+    }
+
+    fn parse_monkey(s: &str) -> IResult<&str, Monkey> {
+        let (s, number) = parse_monkey_line(s)?;
+        let (s, starting_items) = parse_starting_items(s)?;
+        let (s, expression) = parse_arithmetic_expression(s)?;
+        let (s, _) = tag("  Test: divisible by ")(s)?;
+        let (s, test_divisible_by) = terminated(verify(u64, |&d| d != 0), line_ending)(s)?;
+
+        let (s, if_true_throw_to) = terminated(preceded(tag("    If true: throw to monkey "), u64), line_ending)(s)?;
+        let (s, if_false_throw_to) = terminated(preceded(tag("    If false: throw to monkey "), u64), line_ending)(s)?;
+
+        let items = starting_items
+            .into_iter()
+            .map(|current_value| Item {
+                worry_level: WorryLevel {
+                    current_value,
+                    remainder_if_divided_by: HashMap::default(),
+                },
+            })
+            .collect();
+
+        let monkey = Monkey {
+            number,
+            items,
+            inspected_items: 0,
+            expression,
+            test_divisible_by,
+            if_true_throw_to,
+            if_false_throw_to,
+        };
+        Ok((s, monkey))
+    }
+
+    fn parse_monkey_line(s: &str) -> IResult<&str, u64> {
+        let (s, _) = tag("Monkey ")(s)?;
+        let (s, number) = u64(s)?;
+        let (s, _) = char(':')(s)?;
+        let (s, _) = line_ending(s)?;
+        Ok((s, number))
+    }
+    #[test]
+    fn test_parse_monkey_line() {
+        assert_eq!(parse_monkey_line("Monkey 0:\n").unwrap().1, 0);
+    }
+
+    fn parse_starting_items(s: &str) -> IResult<&str, Vec<u64>> {
+        let (s, _) = tag("  Starting items:")(s)?;
+        terminated(preceded(char(' '), separated_list0(tag(", "), u64)), line_ending)(s)
+    }
+    #[test]
+    fn test_parse_starting_items() {
+        parse_starting_items("  Starting items: 79, 98\n").unwrap();
+        parse_starting_items("  Starting items: 74\n").unwrap();
+    }
+
+    fn parse_arithmetic_expression(s: &str) -> IResult<&str, ArithmeticExpression> {
+        let (s, _) = tag("  Operation: new = old ")(s)?;
+        let (s, op) = one_of("+*")(s)?;
+        let operation = match op {
+            '+' => Operation::AddAssign,
+            '*' => Operation::MulAssign,
+            _ => unreachable!(),
+        };
+        let (s, _) = char(' ')(s)?;
+
+        let rhs_value = map(u64, Operand::Value);
+        let rhs_self = value(Operand::Old, tag("old"));
+        let (s, rhs) = alt((rhs_value, rhs_self))(s)?;
+
+        let (s, _) = line_ending(s)?;
+
+        let expression = ArithmeticExpression { operation, operand: rhs };
+
+        Ok((s, expression))
+    }
+    #[test]
+    fn test_parse_operation() {
+        parse_arithmetic_expression("  Operation: new = old * 19\n").unwrap();
+        parse_arithmetic_expression("  Operation: new = old * old\n").unwrap();
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// What the operation of a monkey does to worry levels.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Kind {
+        Add,
+
+        /// Multiply by a number or by two, with `old + old`.
+        Multiply,
+
+        /// Square with `old * old`.
+        Square,
+    }
+
+    /// Random notes on `size` monkeys, at least two.
+    ///
+    /// Monkeys that multiply only throw to monkeys that add, and no monkey throws to monkeys that square, so that worry
+    /// levels stay small in part 1. Monkeys that square only do so to their starting items.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        let n_monkeys = size.max(2);
+        // The first monkey adds and the second does not square, so that every monkey has a monkey to throw to.
+        let kinds: Vec<Kind> = (0..n_monkeys)
+            .map(|idx| match idx {
+                0 => Kind::Add,
+                _ if rng.chance(1, 2) => Kind::Add,
+                2.. if rng.chance(1, 4) => Kind::Square,
+                _ => Kind::Multiply,
+            })
+            .collect();
+        let monkeys: Vec<String> = (0..n_monkeys)
+            .map(|idx| {
+                let targets: Vec<usize> = (0..n_monkeys)
+                    .filter(|&other| other != idx && kinds[other] != Kind::Square)
+                    .filter(|&other| kinds[idx] == Kind::Add || kinds[other] == Kind::Add)
+                    .collect();
+                let items: Vec<String> = (0..rng.range(0..=4)).map(|_| rng.range(50..=99).to_string()).collect();
+                let operation = match kinds[idx] {
+                    Kind::Add => format!("+ {}", rng.range(1..=8)),
+                    Kind::Multiply if rng.chance(1, 4) => String::from("+ old"),
+                    Kind::Multiply => format!("* {}", rng.range(2..=8)),
+                    Kind::Square => String::from("* old"),
+                };
+                format!(
+                    "Monkey {idx}:\n  Starting items: {}\n  Operation: new = old {operation}\n  Test: divisible by {}\n    \
+                     If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    items.join(", "),
+                    rng.choose(&PRIMES),
+                    rng.choose(&targets),
+                    rng.choose(&targets)
+                )
+            })
+            .collect();
+        monkeys.join("\n")
+    }
+}
+
+/// Brute-force solutions, which keep the worry levels in full and so only work for a few rounds.
+#[cfg(test)]
+mod reference {
+    use super::*;
+
+    /// # Panics
+    ///
+    /// Will panic if a worry level does not fit in a `u128`.
+    pub fn part_2(monkeys: &[Monkey], params: &Params) -> u64 {
+        let mut items: Vec<VecDeque<u128>> = monkeys
+            .iter()
+            .map(|monkey| monkey.items.iter().map(|item| item.worry_level.current_value as u128).collect())
+            .collect();
+        let mut inspected_items = vec![0; monkeys.len()];
+        for _round in 1..=params.part_2_rounds {
+            for (idx, monkey) in monkeys.iter().enumerate() {
+                while let Some(worry_level) = items[idx].pop_front() {
+                    inspected_items[idx] += 1;
+                    let rhs = match monkey.expression.operand {
+                        Operand::Value(v) => v as u128,
+                        Operand::Old => worry_level,
+                    };
+                    let worry_level = match monkey.expression.operation {
+                        Operation::AddAssign => worry_level.checked_add(rhs),
+                        Operation::MulAssign => worry_level.checked_mul(rhs),
+                    }
+                    .expect("worry level fits in a u128");
+                    let throw_to = if worry_level % monkey.test_divisible_by as u128 == 0 {
+                        monkey.if_true_throw_to
+                    } else {
+                        monkey.if_false_throw_to
+                    };
+                    items[throw_to as usize].push_back(worry_level);
+                }
+            }
+        }
+        inspected_items.sort_unstable_by(|a, b| b.cmp(a));
+        inspected_items[0] * inspected_items[1]
+    }
+}
+
+static EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("10605"),
+    part_2: Some("2713310158"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 10605);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 2713310158);
+}
+
+#[test]
+fn part_2_agrees_with_reference() {
+    let params = Params {
+        part_1_rounds: 20,
+        part_2_rounds: 10,
+    };
+    crate::differential::assert_agree(0..50, |rng| {
+        let monkeys = parser::parse(&generator::generate(rng, 6)).unwrap();
+        (part_2(&monkeys, &params).unwrap(), reference::part_2(&monkeys, &params))
+    });
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(
+        0..20,
+        |rng| generator::generate(rng, 6),
+        parser::parse,
+        |monkeys| monkeys.iter().map(Monkey::to_string).collect::<Vec<_>>().join("\n"),
+    );
+}
+
+#[test]
+fn part_1_fails_if_worry_levels_overflow() {
+    let notes = "\
+Monkey 0:
+  Starting items: 4294967296
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+    let monkeys = parser::parse(notes).unwrap();
+    assert_eq!(
+        part_1(&monkeys, &Params::default()),
+        Err(PuzzleError::unsolvable("a worry level gets too large"))
+    );
+    assert!(part_2(&monkeys, &Params::default()).is_ok());
+}
+
+#[test]
+fn parts_fail_if_a_monkey_throws_to_an_unknown_monkey() {
+    let notes = "\
+Monkey 0:
+  Starting items: 7
+  Operation: new = old + 1
+  Test: divisible by 5
+    If true: throw to monkey 1
+    If false: throw to monkey 7
+";
+    let monkeys = parser::parse(notes).unwrap();
+    let expected = Err(PuzzleError::invalid("monkey 0 cannot throw to monkey 7"));
+    assert_eq!(part_1(&monkeys, &Params::default()), expected);
+    assert_eq!(part_2(&monkeys, &Params::default()), expected);
+}
+
+#[test]
+fn validate_checks_that_monkeys_are_numbered_in_order() {
+    let notes = "\
+Monkey 0:
+  Starting items: 7
+  Operation: new = old + 1
+  Test: divisible by 5
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 2:
+  Starting items: 8
+  Operation: new = old + 1
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+    assert_eq!(validate(&parser::parse(notes).unwrap()), ["monkey 1 is numbered 2"]);
+}
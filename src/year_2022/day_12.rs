@@ -0,0 +1,317 @@
+//! Day 12: Hill Climbing Algorithm
+//!
+//! https://adventofcode.com/2022/day/12
+
+use crate::error::PuzzleError;
+use crate::lib::graph::Graph;
+use crate::lib::graph::GraphEdge;
+use crate::lib::graph::GraphEdgeCost;
+use crate::lib::graph::dijkstra;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::borrow::Cow;
+
+pub fn part_1(heightmap: &Heightmap) -> Result<usize, PuzzleError> {
+    let start_node = heightmap.find_start()?;
+    let end_node = heightmap.find_end()?;
+    dijkstra::shortest_path(heightmap, &start_node, &end_node).ok_or_else(no_path)
+}
+
+pub fn part_2(heightmap: &Heightmap) -> Result<usize, PuzzleError> {
+    let end_node = heightmap.find_end()?;
+    heightmap
+        .find_coords_with_height('a')
+        .iter()
+        .flat_map(|start_node| dijkstra::shortest_path(heightmap, start_node, &end_node))
+        .min()
+        .ok_or_else(no_path)
+}
+
+fn no_path() -> PuzzleError {
+    PuzzleError::unsolvable("there is no path to the best signal")
+}
+
+/// Problems with the heightmap, which must have exactly one start `S` and one end `E`.
+pub fn validate(heightmap: &Heightmap) -> Vec<String> {
+    [(Node::Start, 'S'), (Node::End, 'E')]
+        .into_iter()
+        .filter_map(|(kind, c)| match heightmap.nodes.iter().filter(|&&node| node == kind).count() {
+            0 => Some(format!("heightmap has no {c}")),
+            1 => None,
+            n => Some(format!("heightmap has {n} of {c}, expected exactly one")),
+        })
+        .collect()
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    type Input<'i> = Heightmap;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+type RowIdx = usize;
+type ColIdx = usize;
+type Coord = (RowIdx, ColIdx);
+
+/// 2D rectangular height map.
+pub struct Heightmap {
+    n_rows: usize,
+    n_cols: usize,
+
+    /// Nodes, where index of (row, col) from top left is: row * width + col.
+    nodes: Vec<Node>,
+}
+impl<'g> Graph<'g> for Heightmap {
+    type Node = Coord;
+
+    type Edge = Edge;
+
+    /// Neighboring nodes that are at most 1 higher than current node.
+    fn edges(&self, from: &Self::Node) -> Vec<Self::Edge> {
+        let from_node = self.get_node(*from).unwrap();
+        let from_height = from_node.height();
+
+        let mut v = vec![];
+        if from.0 > 0 {
+            v.push((from.0 - 1, from.1));
+        }
+        v.push((from.0 + 1, from.1));
+        if from.1 > 0 {
+            v.push((from.0, from.1 - 1));
+        }
+        v.push((from.0, from.1 + 1));
+
+        v.into_iter()
+            .filter(|coord| {
+                let Some(node) = self.get_node(*coord) else {
+                    return false;
+                };
+                from_height + 1 >= node.height()
+            })
+            .map(|coord| Edge { to: coord })
+            .collect()
+    }
+}
+
+pub struct Edge {
+    to: Coord,
+}
+impl<'g> GraphEdge<'g> for Edge {
+    type Node = Coord;
+    fn to(&self) -> Cow<'g, Self::Node> {
+        Cow::Owned(self.to)
+    }
+}
+impl GraphEdgeCost for Edge {
+    type Cost = usize;
+    fn cost(&self) -> Self::Cost {
+        1
+    }
+}
+
+impl Heightmap {
+    fn get_node(&self, coord: Coord) -> Option<Node> {
+        let (row, col) = coord;
+        if row < self.n_rows && col < self.n_cols {
+            Some(self.nodes[row * self.n_cols + col])
+        } else {
+            None
+        }
+    }
+
+    fn find_coords_with_height(&self, c: char) -> Vec<Coord> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_idx, node)| match node {
+                Node::Normal(h) => c == *h,
+                Node::Start => c == 'a',
+                Node::End => c == 'z',
+            })
+            .map(|(idx, _node)| {
+                let row = idx / self.n_cols;
+                let col = idx % self.n_cols;
+                (row, col)
+            })
+            .collect()
+    }
+
+    fn find_start(&self) -> Result<Coord, PuzzleError> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .find(|(_idx, node)| matches!(node, Node::Start))
+            .map(|(idx, _node)| {
+                let row = idx / self.n_cols;
+                let col = idx % self.n_cols;
+                (row, col)
+            })
+            .ok_or_else(|| PuzzleError::invalid("heightmap has no S"))
+    }
+
+    fn find_end(&self) -> Result<Coord, PuzzleError> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .find(|(_idx, node)| matches!(node, Node::End))
+            .map(|(idx, _node)| {
+                let row = idx / self.n_cols;
+                let col = idx % self.n_cols;
+                (row, col)
+            })
+            .ok_or_else(|| PuzzleError::invalid("heightmap has no E"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    Normal(char),
+    Start,
+    End,
+}
+impl Node {
+    fn height(&self) -> usize {
+        let c: char = match self {
+            Node::Normal(char) => *char,
+            Node::Start => 'a',
+            Node::End => 'z',
+        };
+        c as usize
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Heightmap, PuzzleError> {
+        parse_all(s, parse_heightmap)
+    }
+
+    fn parse_heightmap(s: &str) -> IResult<&str, Heightmap> {
+        let is_rectangular = |rows: &Vec<Vec<Node>>| rows.iter().all(|row| row.len() == rows[0].len());
+        let (s, rows) = verify(many1(parse_row), is_rectangular)(s)?;
+
+        let heightmap = Heightmap {
+            n_rows: rows.len(),
+            n_cols: rows.first().unwrap().len(),
+            nodes: rows.into_iter().flat_map(|v| v.into_iter()).collect(),
+        };
+
+        Ok((s, heightmap))
+    }
+
+    fn parse_row(s: &str) -> IResult<&str, Vec<Node>> {
+        let start = value(Node::Start, char('S'));
+        let end = value(Node::End, char('E'));
+        let normal = map(satisfy(|c| c.is_ascii_lowercase()), Node::Normal);
+        terminated(many1(alt((start, end, normal))), line_ending)(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random heightmap with `size` rows, at least one, and enough columns to climb from `a` to `z`.
+    ///
+    /// A path that climbs steadily along the top row and down the last column leads from `S` in the top left to `E` in
+    /// the bottom right, so that the best signal can always be reached.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let n_rows = size.max(1);
+        let min_cols = 27usize.saturating_sub(n_rows).max(2);
+        let n_cols = rng.range(min_cols..=min_cols + n_rows);
+        let path_len = n_rows + n_cols - 2;
+
+        let mut input = String::new();
+        for row in 0..n_rows {
+            for col in 0..n_cols {
+                input.push(if (row, col) == (0, 0) {
+                    'S'
+                } else if (row, col) == (n_rows - 1, n_cols - 1) {
+                    'E'
+                } else if row == 0 || col == n_cols - 1 {
+                    (b'a' + ((row + col) * 25 / path_len) as u8) as char
+                } else {
+                    rng.range(b'a'..=b'z') as char
+                });
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+static EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("31"),
+    part_2: Some("29"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 31);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 29);
+}
+
+#[test]
+fn test_validate() {
+    assert!(validate(&parser::parse(EXAMPLE).unwrap()).is_empty());
+    assert_eq!(
+        validate(&parser::parse("abE\nEca\n").unwrap()),
+        vec![
+            String::from("heightmap has no S"),
+            String::from("heightmap has 2 of E, expected exactly one")
+        ]
+    );
+}
@@ -0,0 +1,262 @@
+//! Day 13: Distress Signal
+//!
+//! https://adventofcode.com/2022/day/13
+
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::fmt::Display;
+
+pub fn part_1(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    Ok(pairs
+        .iter()
+        .enumerate()
+        .filter(|(_idx, pair)| pair.first_packet < pair.second_packet)
+        .map(|(idx, _pair)| idx + 1)
+        .sum())
+}
+
+pub fn part_2(pairs: &[Pair]) -> Result<usize, PuzzleError> {
+    let mut packets: Vec<Value> = pairs.iter().flat_map(|p| [p.first_packet.clone(), p.second_packet.clone()]).collect();
+
+    let divider_packet_1 = Value::List(vec![Value::List(vec![Value::Integer(2)])]);
+    let divider_packet_2 = Value::List(vec![Value::List(vec![Value::Integer(6)])]);
+
+    packets.push(divider_packet_1.clone());
+    packets.push(divider_packet_2.clone());
+
+    packets.sort();
+
+    let (divider_packet_1_idx, _) = packets.iter().enumerate().find(|(_, p)| **p == divider_packet_1).unwrap();
+    let (divider_packet_2_idx, _) = packets.iter().enumerate().find(|(_, p)| **p == divider_packet_2).unwrap();
+
+    Ok((divider_packet_1_idx + 1) * (divider_packet_2_idx + 1))
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    type Input<'i> = Vec<Pair>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Distress Signal"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pair {
+    first_packet: Value,
+    second_packet: Value,
+}
+impl Display for Pair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.first_packet)?;
+        writeln!(f, "{}", self.second_packet)
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+enum Value {
+    List(Vec<Self>),
+    Integer(u64),
+}
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            (Value::List(_), Value::Integer(_)) => {
+                let b = Value::List(vec![other.clone()]);
+                self.cmp(&b)
+            }
+            (Value::Integer(_), Value::List(_)) => {
+                let a = Value::List(vec![self.clone()]);
+                a.cmp(other)
+            }
+            (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        }
+    }
+}
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::List(values) => {
+                write!(f, "[")?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Integer(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Pair>, PuzzleError> {
+        parse_all(s, separated_list0(line_ending, parse_pair))
+    }
+
+    fn parse_pair(s: &str) -> IResult<&str, Pair> {
+        let (s, (first_packet, second_packet)) = pair(parse_packet_line, parse_packet_line)(s)?;
+        let p = Pair { first_packet, second_packet };
+        Ok((s, p))
+    }
+
+    pub(super) fn parse_packet_line(s: &str) -> IResult<&str, Value> {
+        terminated(parse_value, line_ending)(s)
+    }
+
+    fn parse_value(s: &str) -> IResult<&str, Value> {
+        let list = map(delimited(char('['), separated_list0(char(','), parse_value), char(']')), Value::List);
+        let integer = map(u64, Value::Integer);
+        alt((list, integer))(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` pairs of packets, at least one.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1)).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0))).collect();
+        pairs.join("\n")
+    }
+
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let values: Vec<String> = (0..rng.range(0..=5))
+            .map(|_| {
+                if depth < 4 && rng.chance(1, 3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.range(0..=10).to_string()
+                }
+            })
+            .collect();
+        format!("[{}]", values.join(","))
+    }
+}
+
+static EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("13"),
+    part_2: Some("140"),
+    params: &[],
+}];
+
+#[cfg(test)]
+static EXAMPLE_PART_2: &str = "\
+[]
+[[]]
+[[[]]]
+[1,1,3,1,1]
+[1,1,5,1,1]
+[[1],[2,3,4]]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[[1],4]
+[[2]]
+[3]
+[[4,4],4,4]
+[[4,4],4,4,4]
+[[6]]
+[7,7,7]
+[7,7,7,7]
+[[8,7,6]]
+[9]
+";
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 13);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 140);
+}
+
+#[test]
+fn ord() {
+    use crate::nom_complete::*;
+    let mut packets = all_consuming(many0(parser::parse_packet_line))(EXAMPLE_PART_2).unwrap().1;
+    let expected = packets.clone();
+    packets.sort();
+    assert_eq!(packets, expected);
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(
+        0..20,
+        |rng| generator::generate(rng, 10),
+        parser::parse,
+        |pairs| pairs.iter().map(Pair::to_string).collect::<Vec<_>>().join("\n"),
+    );
+}
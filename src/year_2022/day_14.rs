@@ -2,23 +2,32 @@
 //!
 //! https://adventofcode.com/2022/day/14
 
+use crate::cancel;
+use crate::error::PuzzleError;
 use crate::lib::vector_2d::Vector2D;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 use std::collections::HashMap;
 
-pub fn part_1(input: &str) -> usize {
-    let paths = parser::parse(input);
-
+pub fn part_1(paths: &[PathOfRock]) -> Result<usize, PuzzleError> {
     // Find largest y value of rock so we know when a corn of sand would be falling endlessly.
-    let max_y_of_rock = paths.iter().flat_map(|p| p.iter()).map(|v| v.y).max().unwrap();
+    let max_y_of_rock = paths
+        .iter()
+        .flat_map(|p| p.iter())
+        .map(|v| v.y)
+        .max()
+        .ok_or_else(|| PuzzleError::invalid("there are no paths of rock"))?;
 
     // Build world
     let mut world: HashMap<Vector2D<i64>, Element> = HashMap::new();
     for path in paths {
-        let mut path_iter = path.into_iter();
+        let mut path_iter = path.iter().copied();
         let mut current_point = path_iter.next().unwrap();
         world.insert(current_point, Element::Rock);
         for next_point_in_path in path_iter {
             while current_point != next_point_in_path {
+                cancel::check()?;
                 let diff = next_point_in_path - current_point;
                 let step = diff.clamp_x(-1, 1).clamp_y(-1, 1);
                 current_point += step;
@@ -31,10 +40,13 @@ pub fn part_1(input: &str) -> usize {
     let spawn_point: Vector2D<i64> = Vector2D::from((500, 0));
     let mut units_of_sand_come_to_rest = 0;
     'main_loop: loop {
-        debug_assert!(!world.contains_key(&spawn_point), "spawn point should be air");
+        if world.contains_key(&spawn_point) {
+            return Err(PuzzleError::unsolvable("the sand piles up to the source without falling into the abyss"));
+        }
         let mut current_point = spawn_point;
 
         loop {
+            cancel::check()?;
             if current_point.y > max_y_of_rock {
                 // End of simulation
                 break 'main_loop;
@@ -55,25 +67,29 @@ pub fn part_1(input: &str) -> usize {
         }
     }
 
-    units_of_sand_come_to_rest
+    Ok(units_of_sand_come_to_rest)
 }
 
-pub fn part_2(input: &str) -> usize {
-    let paths = parser::parse(input);
-
+pub fn part_2(paths: &[PathOfRock]) -> Result<usize, PuzzleError> {
     // Find largest y value of rock so we know when a corn of sand would be falling endlessly.
-    let max_y_of_rock = paths.iter().flat_map(|p| p.iter()).map(|v| v.y).max().unwrap();
+    let max_y_of_rock = paths
+        .iter()
+        .flat_map(|p| p.iter())
+        .map(|v| v.y)
+        .max()
+        .ok_or_else(|| PuzzleError::invalid("there are no paths of rock"))?;
 
     let floor = max_y_of_rock + 2;
 
     // Build world
     let mut world: HashMap<Vector2D<i64>, Element> = HashMap::new();
     for path in paths {
-        let mut path_iter = path.into_iter();
+        let mut path_iter = path.iter().copied();
         let mut current_point = path_iter.next().unwrap();
         world.insert(current_point, Element::Rock);
         for next_point_in_path in path_iter {
             while current_point != next_point_in_path {
+                cancel::check()?;
                 let diff = next_point_in_path - current_point;
                 let step = diff.clamp_x(-1, 1).clamp_y(-1, 1);
                 current_point += step;
@@ -94,6 +110,7 @@ pub fn part_2(input: &str) -> usize {
         let mut current_point = spawn_point;
 
         loop {
+            cancel::check()?;
             if current_point.y + 1 >= floor {
                 // Deposit here.
                 world.insert(current_point, Element::Sand);
@@ -116,10 +133,48 @@ pub fn part_2(input: &str) -> usize {
         }
     }
 
-    units_of_sand_come_to_rest
+    Ok(units_of_sand_come_to_rest)
+}
+
+pub struct Day14;
+impl Solution for Day14 {
+    type Input<'i> = Vec<PathOfRock>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
 }
 
-type PathOfRock = Vec<Vector2D<i64>>;
+pub type PathOfRock = Vec<Vector2D<i64>>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Element {
@@ -127,12 +182,12 @@ enum Element {
     Sand,
 }
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<PathOfRock> {
-        all_consuming(many0(parse_path_of_rock_line))(s).unwrap().1
+    pub fn parse(s: &str) -> Result<Vec<PathOfRock>, PuzzleError> {
+        parse_all(s, many0(parse_path_of_rock_line))
     }
 
     fn parse_path_of_rock_line(s: &str) -> IResult<&str, PathOfRock> {
@@ -144,18 +199,64 @@ mod parser {
     }
 }
 
-#[cfg(test)]
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random scan of `size` paths of rock, at least one.
+    ///
+    /// The rock is narrow compared to how deep it is below the source of the sand, so that sand cannot pile up to the
+    /// source before it flows into the abyss in part 1.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let (min_x, max_x) = (480, 520);
+        let (min_y, max_y) = (25, 40 + size as i64 / 2);
+        (0..size.max(1))
+            .map(|_| {
+                let mut point: (i64, i64) = (rng.range(min_x..=max_x), rng.range(min_y..=max_y));
+                let mut points = vec![point];
+                for _ in 0..rng.range(1..=4) {
+                    let length = rng.range(1..=8);
+                    if rng.chance(1, 2) {
+                        point.0 = (point.0 + rng.range(-length..=length)).clamp(min_x, max_x);
+                    } else {
+                        point.1 = (point.1 + rng.range(-length..=length)).clamp(min_y, max_y);
+                    }
+                    points.push(point);
+                }
+                let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
+
 static EXAMPLE: &str = "\
 498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9
 ";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("24"),
+    part_2: Some("93"),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 24);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 24);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE), 93);
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 93);
+}
+
+#[test]
+fn part_1_fails_if_the_sand_blocks_the_source() {
+    let paths = parser::parse("495,0 -> 495,2 -> 505,2 -> 505,0\n").unwrap();
+    assert_eq!(
+        part_1(&paths),
+        Err(PuzzleError::unsolvable("the sand piles up to the source without falling into the abyss"))
+    );
 }
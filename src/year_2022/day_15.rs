@@ -0,0 +1,368 @@
+//! Day 15: Beacon Exclusion Zone
+//!
+//! https://adventofcode.com/2022/day/15
+
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::lib::vector_2d::Vector2D;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::cmp::max;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+pub fn part_1(sensors: &[Sensor], params: &Params) -> Result<i64, PuzzleError> {
+    Ok(count_positions_where_a_beacon_cannot_be_present(sensors, params.y))
+}
+
+pub fn part_2(sensors: &[Sensor], params: &Params) -> Result<i64, PuzzleError> {
+    let beacon = find_distress_beacon(sensors, params.limit)?;
+    tuning_frequency(beacon).ok_or_else(|| PuzzleError::unsolvable("the tuning frequency is too large"))
+}
+
+/// Largest distance from 0 of a coordinate, which keeps the distances between positions far from overflowing.
+const MAX_COORDINATE: i64 = 1 << 40;
+
+/// Problems with the positions of the sensors and beacons, which must be within [`MAX_COORDINATE`] of 0.
+pub fn validate(sensors: &[Sensor]) -> Vec<String> {
+    let within = -MAX_COORDINATE..=MAX_COORDINATE;
+    sensors
+        .iter()
+        .flat_map(|sensor| [sensor.at, sensor.closest_beacon])
+        .filter(|position| !within.contains(&position.x) || !within.contains(&position.y))
+        .map(|position| format!("position x={}, y={} is too far out", position.x, position.y))
+        .collect()
+}
+
+pub struct Day15;
+impl Solution for Day15 {
+    type Input<'i> = Vec<Sensor>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input, params).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Row in which to count the positions where a beacon cannot be present.
+    pub y: i64,
+
+    /// Largest coordinate of the distress beacon.
+    pub limit: i64,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            y: 2_000_000,
+            limit: 4_000_000,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["y", "limit"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "y" => self.y = params::parse(name, value)?,
+            "limit" => self.limit = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+fn find_distress_beacon(sensors: &[Sensor], limit: i64) -> Result<Vector2D<i64>, PuzzleError> {
+    'rows: for row in 0..=limit {
+        cancel::check()?;
+        let mut ranges: Vec<RangeInclusive<i64>> = sensors.iter().filter_map(|s| s.x_range_within_radius_given_y(row)).collect();
+        ranges.sort_by_key(|r| *r.start());
+
+        let mut col = 0;
+        for range in ranges {
+            if col > limit {
+                continue 'rows;
+            }
+            if *range.start() > col {
+                return Ok(Vector2D::from((col, row)));
+            }
+            col = max(col, *range.end() + 1);
+        }
+    }
+
+    Err(PuzzleError::unsolvable("distress beacon not found"))
+}
+
+/// Tuning frequency of the distress beacon, unless it is too large to fit.
+fn tuning_frequency(beacon: Vector2D<i64>) -> Option<i64> {
+    beacon.x.checked_mul(4000000)?.checked_add(beacon.y)
+}
+
+fn count_positions_where_a_beacon_cannot_be_present(sensors: &[Sensor], y: i64) -> i64 {
+    let mut ranges: Vec<RangeInclusive<i64>> = sensors.iter().filter_map(|s| s.x_range_within_radius_given_y(y)).collect();
+    ranges.sort_by_key(|r| *r.start());
+
+    let mut i = i64::MIN;
+    let mut count = 0;
+    for range in ranges {
+        if *range.end() > i {
+            if *range.start() > i {
+                count += 1;
+                i = *range.start();
+            }
+            count += range.end() - i;
+            i = *range.end();
+        }
+    }
+
+    let beacons: HashSet<Vector2D<i64>> = sensors.iter().map(|s| s.closest_beacon).collect();
+    for beacon in beacons {
+        if beacon.y == y {
+            count -= 1;
+        }
+    }
+
+    count
+}
+
+pub struct Sensor {
+    at: Vector2D<i64>,
+    closest_beacon: Vector2D<i64>,
+}
+
+impl Sensor {
+    /// Return the x-range of points that have a manhattan distance to the sensor that is
+    /// less or equal to the manhattan distance from the sensor to its nearest beacon.
+    ///
+    /// Return None if there are no such points.
+    ///
+    /// The sensor and beacon must be within [`MAX_COORDINATE`] of 0, but `y` can be anything.
+    fn x_range_within_radius_given_y(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let manhattan_dist_from_sensor_to_beacon = self.at.manhattan_distance(self.closest_beacon);
+        let distance_from_sensor_to_y_plane = self.at.y.abs_diff(y);
+
+        if distance_from_sensor_to_y_plane <= manhattan_dist_from_sensor_to_beacon as u64 {
+            let x_range_to_either_side = manhattan_dist_from_sensor_to_beacon - distance_from_sensor_to_y_plane as i64;
+            Some((self.at.x - x_range_to_either_side)..=(self.at.x + x_range_to_either_side))
+        } else {
+            None
+        }
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Sensor>, PuzzleError> {
+        parse_all(s, many0(parse_sensor_line))
+    }
+
+    fn parse_sensor_line(s: &str) -> IResult<&str, Sensor> {
+        let (s, _) = tag("Sensor at x=")(s)?;
+        let (s, sensor_x) = i64(s)?;
+        let (s, _) = tag(", y=")(s)?;
+        let (s, sensor_y) = i64(s)?;
+        let (s, _) = tag(": closest beacon is at x=")(s)?;
+        let (s, beacon_x) = i64(s)?;
+        let (s, _) = tag(", y=")(s)?;
+        let (s, beacon_y) = i64(s)?;
+        let (s, _) = line_ending(s)?;
+        Ok((
+            s,
+            Sensor {
+                at: Vector2D::from((sensor_x, sensor_y)),
+                closest_beacon: Vector2D::from((beacon_x, beacon_y)),
+            },
+        ))
+    }
+}
+
+pub mod generator {
+    use super::Params;
+    use crate::rng::Rng;
+
+    /// Random report of `size` sensors, plus eight that leave exactly one position for the distress beacon.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_within(rng, size, Params::default().limit)
+    }
+
+    /// Like [`generate`], with the distress beacon within the given limit instead of that of the real puzzle.
+    pub fn generate_within(rng: &mut Rng, size: usize, limit: i64) -> String {
+        let distress = (rng.range(0..=limit), rng.range(0..=limit));
+
+        // Sensors straight across from the distress beacon cover everything in the search area but its diagonals...
+        let mut sensors = Vec::new();
+        let radius = 2 * limit;
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let sensor = (distress.0 + dx * (radius + 1), distress.1 + dy * (radius + 1));
+            sensors.push((sensor, (sensor.0 + dx * radius, sensor.1 + dy * radius)));
+        }
+        // ...and sensors on the diagonals cover those.
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let sensor = (distress.0 + dx * limit, distress.1 + dy * limit);
+            sensors.push((sensor, (sensor.0 + dx * (2 * limit - 1), sensor.1)));
+        }
+
+        for _ in 0..size {
+            let (sensor, distance) = loop {
+                let sensor = (rng.range(0..=limit), rng.range(0..=limit));
+                let distance = (sensor.0 - distress.0).abs() + (sensor.1 - distress.1).abs();
+                if distance > 1 {
+                    break (sensor, distance);
+                }
+            };
+            let radius = rng.range(1..=distance - 1);
+            let dx = rng.range(-radius..=radius);
+            let dy = if rng.chance(1, 2) { radius - dx.abs() } else { dx.abs() - radius };
+            sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+        }
+
+        rng.shuffle(&mut sensors);
+        sensors
+            .into_iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.0, sensor.1, beacon.0, beacon.1
+                )
+            })
+            .collect()
+    }
+}
+
+/// Brute-force solutions, which check every position and so only work for small coordinates.
+#[cfg(test)]
+mod reference {
+    use super::*;
+
+    fn radius(sensor: &Sensor) -> i64 {
+        sensor.at.manhattan_distance(sensor.closest_beacon)
+    }
+
+    fn is_covered(sensors: &[Sensor], position: Vector2D<i64>) -> bool {
+        sensors.iter().any(|sensor| sensor.at.manhattan_distance(position) <= radius(sensor))
+    }
+
+    pub fn part_1(sensors: &[Sensor], params: &Params) -> i64 {
+        let min_x = sensors.iter().map(|sensor| sensor.at.x - radius(sensor)).min().unwrap_or(0);
+        let max_x = sensors.iter().map(|sensor| sensor.at.x + radius(sensor)).max().unwrap_or(0);
+        (min_x..=max_x)
+            .map(|x| Vector2D::from((x, params.y)))
+            .filter(|&position| is_covered(sensors, position) && sensors.iter().all(|sensor| sensor.closest_beacon != position))
+            .count() as i64
+    }
+
+    pub fn part_2(sensors: &[Sensor], params: &Params) -> Option<i64> {
+        (0..=params.limit)
+            .flat_map(|y| (0..=params.limit).map(move |x| Vector2D::from((x, y))))
+            .find(|&position| !is_covered(sensors, position))
+            .and_then(tuning_frequency)
+    }
+}
+
+static EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("26"),
+    part_2: Some("56000011"),
+    params: &[("y", "10"), ("limit", "20")],
+}];
+
+#[cfg(test)]
+static EXAMPLE_PARAMS: Params = Params { y: 10, limit: 20 };
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap(), &EXAMPLE_PARAMS).unwrap(), 26);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &EXAMPLE_PARAMS).unwrap(), 56000011);
+}
+
+#[test]
+fn agrees_with_reference() {
+    crate::differential::assert_agree(0..50, |rng| {
+        let params = Params {
+            y: rng.range(0..=20),
+            limit: 20,
+        };
+        let sensors = parser::parse(&generator::generate_within(rng, 5, params.limit)).unwrap();
+        (
+            (part_1(&sensors, &params).unwrap(), part_2(&sensors, &params).ok()),
+            (reference::part_1(&sensors, &params), reference::part_2(&sensors, &params)),
+        )
+    });
+}
+
+#[test]
+fn validate_rejects_positions_too_far_out() {
+    let report = "Sensor at x=0, y=-9223372036854775808: closest beacon is at x=1, y=0\n";
+    assert_eq!(
+        validate(&parser::parse(report).unwrap()),
+        ["position x=0, y=-9223372036854775808 is too far out"]
+    );
+    assert!(validate(&parser::parse(EXAMPLE).unwrap()).is_empty());
+}
+
+#[test]
+fn part_1_does_not_overflow_on_far_away_rows() {
+    let sensors = parser::parse(EXAMPLE).unwrap();
+    assert_eq!(part_1(&sensors, &Params { y: i64::MIN, limit: 20 }), Ok(0));
+    assert_eq!(part_1(&sensors, &Params { y: i64::MAX, limit: 20 }), Ok(0));
+}
@@ -0,0 +1,437 @@
+//! Day 16: Proboscidea Volcanium
+//!
+//! https://adventofcode.com/2022/day/16
+//!
+//! This is not the prettiest or most performant of solutions.
+//! Could definitely be cleaned up!
+
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::lib::graph::Graph;
+use crate::lib::graph::GraphEdge;
+use crate::lib::graph::GraphEdgeCost;
+use crate::lib::graph::dijkstra;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::borrow::Cow;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub fn part_1(network_of_pipes: &NetworkOfPipes<'_>) -> Result<i64, PuzzleError> {
+    let minutes_to_move_from_a_valve_to_any_other_valve = compute_minutes_to_move_from_a_valve_to_any_other_valve(network_of_pipes);
+    let mut opened_valves: HashSet<&str> = HashSet::new();
+
+    let mut actors = vec![Actor {
+        minutes: 30,
+        name_of_current_valve: "AA",
+    }];
+
+    let pressure_released = dfs(
+        network_of_pipes,
+        &minutes_to_move_from_a_valve_to_any_other_valve,
+        &mut actors,
+        &mut opened_valves,
+    );
+    cancel::check()?;
+    Ok(pressure_released)
+}
+
+pub fn part_2(network_of_pipes: &NetworkOfPipes<'_>) -> Result<i64, PuzzleError> {
+    let minutes_to_move_from_a_valve_to_any_other_valve = compute_minutes_to_move_from_a_valve_to_any_other_valve(network_of_pipes);
+    let mut opened_valves: HashSet<&str> = HashSet::new();
+
+    let mut actors = vec![
+        Actor {
+            minutes: 26,
+            name_of_current_valve: "AA",
+        },
+        Actor {
+            minutes: 26,
+            name_of_current_valve: "AA",
+        },
+    ];
+
+    let pressure_released = dfs(
+        network_of_pipes,
+        &minutes_to_move_from_a_valve_to_any_other_valve,
+        &mut actors,
+        &mut opened_valves,
+    );
+    cancel::check()?;
+    Ok(pressure_released)
+}
+
+pub fn validate(network_of_pipes: &NetworkOfPipes<'_>) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !network_of_pipes.valves.contains_key("AA") {
+        problems.push(String::from("there is no valve AA"));
+    }
+    for name in &network_of_pipes.duplicates {
+        problems.push(format!("valve {name} is defined more than once"));
+    }
+    let mut valves: Vec<&Valve<'_>> = network_of_pipes.valves.values().collect();
+    valves.sort_by_key(|valve| valve.name);
+    for valve in valves {
+        if valve.flow_rate > u64::from(u32::MAX) {
+            problems.push(format!("valve {} has a flow rate that is too large", valve.name));
+        }
+        for name in valve.tunnels_to.iter().filter(|name| !network_of_pipes.valves.contains_key(*name)) {
+            problems.push(format!("valve {} has a tunnel to unknown valve {name}", valve.name));
+        }
+    }
+    problems
+}
+
+pub struct Day16;
+impl Solution for Day16 {
+    type Input<'i> = NetworkOfPipes<'i>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+fn compute_minutes_to_move_from_a_valve_to_any_other_valve<'a>(network_of_pipes: &NetworkOfPipes<'a>) -> HashMap<(&'a str, &'a str), u64> {
+    let mut minutes_to_move_from_a_valve_to_any_other_valve: HashMap<(&str, &str), u64> = HashMap::new();
+    for valve in network_of_pipes.valves.values() {
+        for other in network_of_pipes.valves.values() {
+            if valve == other {
+                continue;
+            }
+            // Valves that cannot be reached are left out.
+            if let Some(minutes) = dijkstra::shortest_path(network_of_pipes, valve, other) {
+                minutes_to_move_from_a_valve_to_any_other_valve.insert((valve.name, other.name), minutes);
+            }
+        }
+    }
+    minutes_to_move_from_a_valve_to_any_other_valve
+}
+
+fn dfs<'a>(
+    network_of_pipes: &'a NetworkOfPipes<'_>,
+    minutes_to_move_from_a_valve_to_any_other_valve: &HashMap<(&'a str, &'a str), u64>,
+    actors: &mut Vec<Actor<'a>>,
+    opened_valves: &mut HashSet<&'a str>,
+) -> i64 {
+    if actors.is_empty() || cancel::is_cancelled() {
+        return 0;
+    }
+
+    let actor = actors.pop().unwrap();
+
+    // Max score by not going further with present actor.
+    let score_1 = dfs(network_of_pipes, minutes_to_move_from_a_valve_to_any_other_valve, actors, opened_valves);
+
+    // Max score by still going further with present actor.
+    let score_2 = network_of_pipes
+        .valves
+        .values()
+        .filter(|v| v.flow_rate > 0)
+        .filter(|v| v.name != actor.name_of_current_valve)
+        .filter_map(|valve| {
+            if opened_valves.contains(valve.name) {
+                return None;
+            }
+
+            let mut minutes = actor.minutes;
+
+            // Move to valve
+            minutes -= *minutes_to_move_from_a_valve_to_any_other_valve.get(&(actor.name_of_current_valve, valve.name))? as i64;
+            // Open valve
+            minutes -= 1;
+            if minutes < 0 {
+                return None;
+            }
+
+            opened_valves.insert(valve.name);
+            actors.push(Actor {
+                minutes,
+                name_of_current_valve: valve.name,
+            });
+            let total_pressure_release =
+                minutes * valve.flow_rate as i64 + dfs(network_of_pipes, minutes_to_move_from_a_valve_to_any_other_valve, actors, opened_valves);
+            actors.pop();
+            opened_valves.remove(valve.name);
+
+            Some(total_pressure_release)
+        })
+        .max()
+        .unwrap_or(0);
+    actors.push(actor);
+
+    max(score_1, score_2)
+}
+
+#[derive(Debug)]
+struct Actor<'a> {
+    minutes: i64,
+    name_of_current_valve: &'a str,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NetworkOfPipes<'i> {
+    /// Valves indexed by name.
+    valves: HashMap<&'i str, Valve<'i>>,
+
+    /// Names of the valves that are defined more than once, of which only the last definition is kept.
+    duplicates: Vec<&'i str>,
+}
+
+impl<'s, 'i> Graph<'s> for NetworkOfPipes<'i>
+where
+    'i: 's,
+{
+    type Node = Valve<'i>;
+    type Edge = Edge<'s, 'i>;
+
+    fn edges(&'s self, from: &Self::Node) -> Vec<Self::Edge> {
+        from.tunnels_to
+            .iter()
+            .map(|name| Edge {
+                to: self.valves.get(name).unwrap(),
+            })
+            .collect()
+    }
+}
+
+pub struct Edge<'r, 'i> {
+    to: &'r Valve<'i>,
+}
+impl<'r, 'i> GraphEdge<'r> for Edge<'r, 'i> {
+    type Node = Valve<'i>;
+
+    fn to(&self) -> Cow<'r, Self::Node> {
+        Cow::Borrowed(self.to)
+    }
+}
+impl<'r, 'i> GraphEdgeCost for Edge<'r, 'i> {
+    type Cost = u64;
+
+    fn cost(&self) -> Self::Cost {
+        1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valve<'a> {
+    name: &'a str,
+    flow_rate: u64,
+    tunnels_to: Vec<&'a str>,
+}
+impl Display for Valve<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tunnels = if self.tunnels_to.len() == 1 {
+            "tunnel leads to valve"
+        } else {
+            "tunnels lead to valves"
+        };
+        write!(
+            f,
+            "Valve {} has flow rate={}; {tunnels} {}",
+            self.name,
+            self.flow_rate,
+            self.tunnels_to.join(", ")
+        )
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<NetworkOfPipes<'_>, PuzzleError> {
+        let p = map(many1(parse_valve_line), |valves| {
+            let mut network_of_pipes = NetworkOfPipes {
+                valves: HashMap::new(),
+                duplicates: Vec::new(),
+            };
+            for valve in valves {
+                if let Some(duplicate) = network_of_pipes.valves.insert(valve.name, valve) {
+                    network_of_pipes.duplicates.push(duplicate.name);
+                }
+            }
+            network_of_pipes
+        });
+        parse_all(s, terminated(p, multispace0))
+    }
+
+    fn parse_valve_line(s: &str) -> IResult<&str, Valve<'_>> {
+        let (s, _) = tag("Valve ")(s)?;
+        let (s, name) = parse_valve_name(s)?;
+        let (s, _) = tag(" has flow rate=")(s)?;
+        let (s, flow_rate) = u64(s)?;
+        let (s, _) = tag("; tunnel")(s)?;
+        let (s, _) = opt(char('s'))(s)?;
+        let (s, _) = tag(" lead")(s)?;
+        let (s, _) = opt(char('s'))(s)?;
+        let (s, _) = tag(" to valve")(s)?;
+        let (s, _) = opt(char('s'))(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, tunnels_to) = separated_list1(tag(", "), parse_valve_name)(s)?;
+        let (s, _) = line_ending(s)?;
+
+        let valve = Valve { name, flow_rate, tunnels_to };
+
+        Ok((s, valve))
+    }
+
+    fn parse_valve_name(s: &str) -> IResult<&str, &str> {
+        take_while1(|c: char| c.is_ascii_uppercase())(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+    use std::collections::BTreeSet;
+
+    /// Random scan of `size` valves, at least two, with tunnels that connect every valve to `AA`.
+    ///
+    /// At most 15 valves have a flow rate, like in the real puzzle inputs, as more would take too long to search.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names: Vec<String> = ('A'..='Z')
+            .flat_map(|first| ('A'..='Z').map(move |second| format!("{first}{second}")))
+            .filter(|name| name != "AA")
+            .collect();
+        rng.shuffle(&mut names);
+        names.insert(0, String::from("AA"));
+        let n_valves = size.clamp(2, names.len());
+
+        // A tree of tunnels connects every valve, and extra tunnels make loops.
+        let mut tunnels = vec![BTreeSet::new(); n_valves];
+        let mut connect = |valve: usize, other: usize| {
+            if valve != other {
+                tunnels[valve].insert(other);
+                tunnels[other].insert(valve);
+            }
+        };
+        for valve in 1..n_valves {
+            connect(valve, rng.index(valve));
+        }
+        for _ in 0..n_valves / 3 {
+            connect(rng.index(n_valves), rng.index(n_valves));
+        }
+
+        let mut flow_rates = vec![0; n_valves];
+        let mut valves: Vec<usize> = (1..n_valves).collect();
+        rng.shuffle(&mut valves);
+        for _ in 0..rng.range(1..=valves.len().min(15)) {
+            flow_rates[valves.pop().unwrap()] = rng.range(1..=25);
+        }
+
+        let mut valves: Vec<usize> = (0..n_valves).collect();
+        rng.shuffle(&mut valves);
+        valves
+            .into_iter()
+            .map(|valve| {
+                let to: Vec<&str> = tunnels[valve].iter().map(|&other| names[other].as_str()).collect();
+                let tunnels_lead_to = if to.len() == 1 {
+                    "tunnel leads to valve"
+                } else {
+                    "tunnels lead to valves"
+                };
+                format!(
+                    "Valve {} has flow rate={}; {tunnels_lead_to} {}\n",
+                    names[valve],
+                    flow_rates[valve],
+                    to.join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+static EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("1651"),
+    part_2: Some("1707"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 1651);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 1707);
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(
+        0..20,
+        |rng| generator::generate(rng, 10),
+        parser::parse,
+        |network| network.valves.values().map(|valve| format!("{valve}\n")).collect(),
+    );
+}
+
+#[test]
+fn validate_finds_every_problem() {
+    let input = "\
+Valve BB has flow rate=1; tunnel leads to valve CC
+Valve CC has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=4294967296; tunnels lead to valves CC, DD
+";
+    assert_eq!(
+        validate(&parser::parse(input).unwrap()),
+        [
+            "there is no valve AA",
+            "valve BB is defined more than once",
+            "valve BB has a flow rate that is too large",
+            "valve BB has a tunnel to unknown valve DD",
+        ]
+    );
+    assert!(parser::parse("Valve  has flow rate=0; tunnel leads to valve AA\n").is_err());
+}
@@ -2,27 +2,98 @@
 //!
 //! https://adventofcode.com/2022/day/17
 
+use crate::cancel;
+use crate::error::PuzzleError;
 use crate::lib::vector_2d::Vector2D;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 use std::cmp::max;
 use std::collections::VecDeque;
 use std::fmt::Display;
 
-pub fn part_1(input: &str) -> usize {
-    let jets = parser::parse(input);
-    let cave = simulate(jets, 2022);
-    cave.past_rows + cave.rows.len() - 1
+pub fn part_1(jets: &[Jet], params: &Params) -> Result<usize, PuzzleError> {
+    simulate(jets, params.part_1_rocks)?.height()
 }
 
 /// Idea with this one is to detect when there is a cycle.
 /// In addition, full lines causes rows below to be forgotten as a memory usage optimization.
-pub fn part_2(input: &str) -> usize {
-    let jets = parser::parse(input);
-    let cave = simulate(jets, 1_000_000_000_000);
-    cave.past_rows + cave.rows.len() - 1
+pub fn part_2(jets: &[Jet], params: &Params) -> Result<usize, PuzzleError> {
+    simulate(jets, params.part_2_rocks)?.height()
 }
 
-fn simulate(jets: Vec<Jet>, limit: usize) -> Cave {
-    let mut jet_pattern = jet_pattern(&jets);
+pub struct Day17;
+impl Solution for Day17 {
+    type Input<'i> = Vec<Jet>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Pyroclastic Flow"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input, params).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Number of rocks to simulate in part 1.
+    pub part_1_rocks: usize,
+
+    /// Number of rocks to simulate in part 2.
+    pub part_2_rocks: usize,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_rocks: 2022,
+            part_2_rocks: 1_000_000_000_000,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["part_1_rocks", "part_2_rocks"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part_1_rocks" => self.part_1_rocks = params::parse(name, value)?,
+            "part_2_rocks" => self.part_2_rocks = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+/// Fails if the tower gets too tall to count its rows.
+fn simulate(jets: &[Jet], limit: usize) -> Result<Cave, PuzzleError> {
+    let mut jet_pattern = jet_pattern(jets);
     let mut rock_pattern = rock_pattern();
     let max_rock_height = 3;
 
@@ -41,6 +112,7 @@ fn simulate(jets: Vec<Jet>, limit: usize) -> Cave {
 
     let mut fallen_rocks = 0;
     while fallen_rocks < limit {
+        cancel::check()?;
         let (rock_shape, rock_pattern_idx) = rock_pattern.next().unwrap();
         let mut falling_rock = FallingRock {
             coord: Vector2D::from((cave.rows.len() as i64 + 3, 2)),
@@ -83,7 +155,10 @@ fn simulate(jets: Vec<Jet>, limit: usize) -> Cave {
                         let remaining_rocks_to_simulate = limit - fallen_rocks;
                         let n_cycles_to_fast_forward = remaining_rocks_to_simulate / rocks_per_cycle;
                         let rows_added_per_cycle = (cave.past_rows + cave.rows.len()) - (snapshot.cave.past_rows + snapshot.cave.rows.len());
-                        cave.past_rows += n_cycles_to_fast_forward * rows_added_per_cycle;
+                        cave.past_rows = n_cycles_to_fast_forward
+                            .checked_mul(rows_added_per_cycle)
+                            .and_then(|rows| rows.checked_add(cave.past_rows))
+                            .ok_or_else(too_tall)?;
                         fallen_rocks += n_cycles_to_fast_forward * rocks_per_cycle;
 
                         snapshots.clear();
@@ -106,7 +181,11 @@ fn simulate(jets: Vec<Jet>, limit: usize) -> Cave {
         }
     }
 
-    cave
+    Ok(cave)
+}
+
+fn too_tall() -> PuzzleError {
+    PuzzleError::unsolvable("the tower gets too tall to count its rows")
 }
 
 fn jet_pattern(jets: &[Jet]) -> impl Iterator<Item = (&Jet, usize)> {
@@ -143,7 +222,7 @@ struct Snapshot {
 }
 
 #[derive(Clone, Copy)]
-enum Jet {
+pub enum Jet {
     Left,
     Right,
 }
@@ -217,6 +296,12 @@ struct Cave {
     past_rows: usize,
     rows: VecDeque<Row>,
 }
+impl Cave {
+    /// Number of rows of rocks, below which there is the floor.
+    fn height(&self) -> Result<usize, PuzzleError> {
+        self.past_rows.checked_add(self.rows.len() - 1).ok_or_else(too_tall)
+    }
+}
 impl Display for Cave {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (idx, row) in self.rows.iter().rev().enumerate() {
@@ -238,12 +323,12 @@ impl Display for Cave {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Row([bool; 7]);
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<Jet> {
-        all_consuming(terminated(many1(parse_jet), multispace0))(s).unwrap().1
+    pub fn parse(s: &str) -> Result<Vec<Jet>, PuzzleError> {
+        parse_all(s, terminated(many1(parse_jet), multispace0))
     }
 
     fn parse_jet(s: &str) -> IResult<&str, Jet> {
@@ -253,15 +338,111 @@ mod parser {
     }
 }
 
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random pattern of `size` jets, at least one.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| *rng.choose(&['<', '>'])).chain(['\n']).collect()
+    }
+}
+
+/// Brute-force solution, which simulates every rock and so only works for a small number of rocks.
 #[cfg(test)]
+mod reference {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Height of the tower after the given number of rocks have come to rest.
+    pub fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+        // Parts of each rock as (x, y), with y pointing up from the bottom left corner.
+        let shapes: [&[(i64, i64)]; 5] = [
+            &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        ];
+
+        // The floor is at y = 0.
+        let mut rock_parts: HashSet<(i64, i64)> = HashSet::new();
+        let mut height = 0;
+        let mut jets = jets.iter().cycle();
+        for shape in shapes.iter().cycle().take(rocks) {
+            let fits = |x: i64, y: i64| {
+                shape
+                    .iter()
+                    .all(|&(dx, dy)| (0..7).contains(&(x + dx)) && y + dy > 0 && !rock_parts.contains(&(x + dx, y + dy)))
+            };
+            let (mut x, mut y) = (2, height + 4);
+            loop {
+                let dx = match jets.next().unwrap() {
+                    Jet::Left => -1,
+                    Jet::Right => 1,
+                };
+                if fits(x + dx, y) {
+                    x += dx;
+                }
+                if !fits(x, y - 1) {
+                    break;
+                }
+                y -= 1;
+            }
+            for &(dx, dy) in *shape {
+                rock_parts.insert((x + dx, y + dy));
+                height = height.max(y + dy);
+            }
+        }
+        height as usize
+    }
+}
+
 static EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("3068"),
+    part_2: Some("1514285714288"),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 3068);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 3068);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE), 1_514_285_714_288);
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 1_514_285_714_288);
+}
+
+#[test]
+fn agrees_with_reference() {
+    let params = Params {
+        part_1_rocks: 500,
+        part_2_rocks: 5000,
+    };
+    crate::differential::assert_agree(0..20, |rng| {
+        let jets = parser::parse(&generator::generate(rng, 40)).unwrap();
+        (
+            (part_1(&jets, &params).unwrap(), part_2(&jets, &params).unwrap()),
+            (
+                reference::tower_height(&jets, params.part_1_rocks),
+                reference::tower_height(&jets, params.part_2_rocks),
+            ),
+        )
+    });
+}
+
+#[test]
+fn part_2_fails_if_the_tower_gets_too_tall() {
+    let params = Params {
+        part_2_rocks: usize::MAX,
+        ..Params::default()
+    };
+    assert_eq!(
+        part_2(&parser::parse(EXAMPLE).unwrap(), &params),
+        Err(PuzzleError::unsolvable("the tower gets too tall to count its rows"))
+    );
 }
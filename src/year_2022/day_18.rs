@@ -0,0 +1,189 @@
+//! Day 18: Boiling Boulders
+//!
+//! https://adventofcode.com/2022/day/18
+
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub fn part_1(lava_cubes: &[Cube]) -> Result<usize, PuzzleError> {
+    let droplet = Droplet {
+        lava_cubes: lava_cubes.iter().copied().collect(),
+    };
+
+    Ok(droplet
+        .lava_cubes
+        .iter()
+        .flat_map(|c| c.neighbors().filter(|n| !droplet.lava_cubes.contains(n)))
+        .count())
+}
+
+pub fn part_2(lava_cubes: &[Cube]) -> Result<usize, PuzzleError> {
+    let droplet = Droplet {
+        lava_cubes: lava_cubes.iter().copied().collect(),
+    };
+    let min_x = droplet.lava_cubes.iter().map(|c| c.0).min().unwrap() - 1;
+    let min_y = droplet.lava_cubes.iter().map(|c| c.1).min().unwrap() - 1;
+    let min_z = droplet.lava_cubes.iter().map(|c| c.2).min().unwrap() - 1;
+    let max_x = droplet.lava_cubes.iter().map(|c| c.0).max().unwrap() + 1;
+    let max_y = droplet.lava_cubes.iter().map(|c| c.1).max().unwrap() + 1;
+    let max_z = droplet.lava_cubes.iter().map(|c| c.2).max().unwrap() + 1;
+
+    // DFS to find all cubes of air surrounding droplet.
+    let mut outside_air: HashSet<Cube> = HashSet::new();
+    let mut stack: Vec<Cube> = Vec::new();
+    let start = Cube(min_x, min_y, min_z);
+    debug_assert!(!droplet.lava_cubes.contains(&start));
+    stack.push(start);
+    outside_air.insert(start);
+    while let Some(cube) = stack.pop() {
+        cancel::check()?;
+        let neighbors: Vec<Cube> = cube
+            .neighbors()
+            .filter(|c| (min_x..=max_x).contains(&c.0) && (min_y..=max_y).contains(&c.1) && (min_z..=max_z).contains(&c.2))
+            .filter(|c| !outside_air.contains(c))
+            .filter(|c| !droplet.lava_cubes.contains(c))
+            .collect();
+        outside_air.extend(neighbors.iter().copied());
+        stack.extend(neighbors);
+    }
+
+    // For every lava cube in the droplet, count its surfaces that border cubes in outside air.
+    Ok(droplet
+        .lava_cubes
+        .iter()
+        .flat_map(|c| c.neighbors().filter(|c| outside_air.contains(c)))
+        .count())
+}
+
+pub struct Day18;
+impl Solution for Day18 {
+    type Input<'i> = Vec<Cube>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Boiling Boulders"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+struct Droplet {
+    lava_cubes: HashSet<Cube>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cube(i64, i64, i64);
+impl Cube {
+    fn neighbors(self) -> impl Iterator<Item = Self> {
+        [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)]
+            .into_iter()
+            .map(move |d| Cube(self.0 + d.0, self.1 + d.1, self.2 + d.2))
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Cube>, PuzzleError> {
+        parse_all(s, terminated(separated_list1(line_ending, parse_cube), multispace0))
+    }
+
+    fn parse_cube(s: &str) -> IResult<&str, Cube> {
+        let (s, x) = i64(s)?;
+        let (s, _) = char(',')(s)?;
+        let (s, y) = i64(s)?;
+        let (s, _) = char(',')(s)?;
+        let (s, z) = i64(s)?;
+        Ok((s, Cube(x, y, z)))
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+    use std::collections::HashSet;
+
+    /// Random scan of `size` different cubes, at least one, which fill about half of the box around them.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let n_cubes = size.max(1);
+        let mut side = 1;
+        while side * side * side < 2 * n_cubes {
+            side += 1;
+        }
+        let max = side as i64 - 1;
+
+        let mut cubes = HashSet::new();
+        let mut input = String::new();
+        while cubes.len() < n_cubes {
+            let cube = (rng.range(0..=max), rng.range(0..=max), rng.range(0..=max));
+            if cubes.insert(cube) {
+                input += &format!("{},{},{}\n", cube.0, cube.1, cube.2);
+            }
+        }
+        input
+    }
+}
+
+static EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("64"),
+    part_2: Some("58"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 64);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 58);
+}
@@ -2,41 +2,129 @@
 //!
 //! https://adventofcode.com/2022/day/19
 
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
 use std::cmp::max;
 use std::collections::HashMap;
+use std::fmt::Display;
 use std::ops::AddAssign;
 use std::ops::SubAssign;
 
-pub fn part_1(input: &str) -> i64 {
-    let blueprints = parser::parse(input);
-    let minutes = 24;
-
-    blueprints
+pub fn part_1(blueprints: &[Blueprint], params: &Params) -> Result<i64, PuzzleError> {
+    let quality_levels = blueprints
         .iter()
         .map(|&blueprint| {
             let mut cache = HashMap::default();
-            let geodes = max_geodes_dfs(blueprint, Robots::default(), Resources::default(), minutes, &mut cache);
+            let geodes = max_geodes_dfs(blueprint, Robots::default(), Resources::default(), params.part_1_minutes, &mut cache);
             (blueprint, geodes)
         })
         .map(|(blueprint, max_geodes)| quality_level(blueprint.id, max_geodes))
-        .sum()
+        .sum();
+    cancel::check()?;
+    Ok(quality_levels)
 }
 
-pub fn part_2(input: &str) -> i64 {
-    let blueprints = parser::parse(input);
-    let minutes = 32;
-
-    blueprints
+pub fn part_2(blueprints: &[Blueprint], params: &Params) -> Result<i64, PuzzleError> {
+    let max_geodes_product = blueprints
         .iter()
-        .inspect(|b| {
-            dbg!(b);
-        })
-        .take(3)
+        .take(params.part_2_blueprints)
         .map(|&blueprint| {
             let mut cache = HashMap::default();
-            max_geodes_dfs(blueprint, Robots::default(), Resources::default(), minutes, &mut cache)
+            max_geodes_dfs(blueprint, Robots::default(), Resources::default(), params.part_2_minutes, &mut cache)
         })
-        .product()
+        .product();
+    cancel::check()?;
+    Ok(max_geodes_product)
+}
+
+pub struct Day19;
+impl Solution for Day19 {
+    type Input<'i> = Vec<Blueprint>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Not Enough Minerals"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input, params).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Minutes to collect geodes in part 1.
+    pub part_1_minutes: Minutes,
+
+    /// Minutes to collect geodes in part 2.
+    pub part_2_minutes: Minutes,
+
+    /// Number of blueprints that remain in part 2.
+    pub part_2_blueprints: usize,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_minutes: 24,
+            part_2_minutes: 32,
+            part_2_blueprints: 3,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["part_1_minutes", "part_2_minutes", "part_2_blueprints"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "part_1_minutes" => self.part_1_minutes = parse_minutes(name, value)?,
+            "part_2_minutes" => self.part_2_minutes = parse_minutes(name, value)?,
+            "part_2_blueprints" => self.part_2_blueprints = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+/// Minutes from the value of a parameter, which cannot be negative.
+fn parse_minutes(name: &str, value: &str) -> Result<Minutes, ParamError> {
+    let minutes = params::parse(name, value)?;
+    if minutes < 0 {
+        return Err(ParamError::Value {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            reason: String::from("minutes cannot be negative"),
+        });
+    }
+    Ok(minutes)
 }
 
 type Minutes = i64;
@@ -56,7 +144,7 @@ fn max_geodes_dfs(
     debug_assert!(resources.obsidian >= 0);
     debug_assert!(resources.geode >= 0);
 
-    if minutes == 0 {
+    if minutes == 0 || cancel::is_cancelled() {
         return resources.geode;
     }
 
@@ -72,7 +160,7 @@ fn max_geodes_dfs(
         let mut robots = robots;
         let mut resources = resources;
         let mut minutes = minutes;
-        let minutes_gathering = max(0, blueprint.ore_robot_ore_cost - resources.ore).div_ceil(robots.ore);
+        let minutes_gathering = minutes_to_gather(blueprint.ore_robot_ore_cost, resources.ore, robots.ore);
         minutes -= minutes_gathering + 1;
         if minutes < 0 {
             break 'ore_robot;
@@ -119,7 +207,7 @@ fn max_geodes_dfs(
         let mut robots = robots;
         let mut resources = resources;
         let mut minutes = minutes;
-        let minutes_gathering = max(0, blueprint.clay_robot_ore_cost - resources.ore).div_ceil(robots.ore);
+        let minutes_gathering = minutes_to_gather(blueprint.clay_robot_ore_cost, resources.ore, robots.ore);
         minutes -= minutes_gathering + 1;
         if minutes < 0 {
             break 'clay_robot;
@@ -167,8 +255,8 @@ fn max_geodes_dfs(
         let mut robots = robots;
         let mut resources = resources;
         let mut minutes = minutes;
-        let minutes_gathering_ore = max(0, blueprint.obsidian_robot_ore_cost - resources.ore).div_ceil(robots.ore);
-        let minutes_gathering_clay = max(0, blueprint.obsidian_robot_clay_cost - resources.clay).div_ceil(robots.clay);
+        let minutes_gathering_ore = minutes_to_gather(blueprint.obsidian_robot_ore_cost, resources.ore, robots.ore);
+        let minutes_gathering_clay = minutes_to_gather(blueprint.obsidian_robot_clay_cost, resources.clay, robots.clay);
         let minutes_gathering = max(minutes_gathering_ore, minutes_gathering_clay);
         minutes -= minutes_gathering + 1;
         if minutes < 0 {
@@ -217,8 +305,8 @@ fn max_geodes_dfs(
         let mut robots = robots;
         let mut resources = resources;
         let mut minutes = minutes;
-        let minutes_gathering_ore = max(0, blueprint.geode_robot_ore_cost - resources.ore).div_ceil(robots.ore);
-        let minutes_gathering_obsidian = max(0, blueprint.geode_robot_obsidian_cost - resources.obsidian).div_ceil(robots.obsidian);
+        let minutes_gathering_ore = minutes_to_gather(blueprint.geode_robot_ore_cost, resources.ore, robots.ore);
+        let minutes_gathering_obsidian = minutes_to_gather(blueprint.geode_robot_obsidian_cost, resources.obsidian, robots.obsidian);
         let minutes_gathering = max(minutes_gathering_ore, minutes_gathering_obsidian);
         minutes -= minutes_gathering + 1;
         if minutes < 0 {
@@ -262,6 +350,12 @@ fn max_geodes_dfs(
     best
 }
 
+/// Minutes for `robots` to gather enough of a resource to pay `cost` with, when `available` has been gathered already.
+fn minutes_to_gather(cost: i64, available: i64, robots: i64) -> Minutes {
+    // Nothing is missing if the cost is zero, even without robots to gather it.
+    if available >= cost { 0 } else { (cost - available).div_ceil(robots) }
+}
+
 fn quality_level(blueprint_id: i64, max_geodes: i64) -> i64 {
     blueprint_id * max_geodes
 }
@@ -316,8 +410,8 @@ impl SubAssign for Resources {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Blueprint {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blueprint {
     id: i64,
     ore_robot_ore_cost: i64,
     clay_robot_ore_cost: i64,
@@ -326,33 +420,47 @@ struct Blueprint {
     geode_robot_ore_cost: i64,
     geode_robot_obsidian_cost: i64,
 }
+impl Display for Blueprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            self.id,
+            self.ore_robot_ore_cost,
+            self.clay_robot_ore_cost,
+            self.obsidian_robot_ore_cost,
+            self.obsidian_robot_clay_cost,
+            self.geode_robot_ore_cost,
+            self.geode_robot_obsidian_cost
+        )
+    }
+}
 
-mod parser {
+pub mod parser {
     use super::*;
     use crate::nom_complete::*;
 
-    pub(super) fn parse(s: &str) -> Vec<Blueprint> {
-        all_consuming(terminated(separated_list1(line_ending, parse_blueprint), multispace0))(s)
-            .unwrap()
-            .1
+    pub fn parse(s: &str) -> Result<Vec<Blueprint>, PuzzleError> {
+        parse_all(s, terminated(separated_list1(line_ending, parse_blueprint), multispace0))
     }
 
     fn parse_blueprint(s: &str) -> IResult<&str, Blueprint> {
-        let (s, id) = delimited(tag("Blueprint "), u64, char(':'))(s)?;
+        let (s, id) = delimited(tag("Blueprint "), u32, char(':'))(s)?;
         let (s, _) = char(' ')(s)?;
-        let (s, ore_robot_ore_cost) = delimited(tag("Each ore robot costs "), u64, tag(" ore."))(s)?;
+        let (s, ore_robot_ore_cost) = delimited(tag("Each ore robot costs "), u32, tag(" ore."))(s)?;
         let (s, _) = char(' ')(s)?;
-        let (s, clay_robot_ore_cost) = delimited(tag("Each clay robot costs "), u64, tag(" ore."))(s)?;
+        let (s, clay_robot_ore_cost) = delimited(tag("Each clay robot costs "), u32, tag(" ore."))(s)?;
         let (s, _) = char(' ')(s)?;
         let (s, (obsidian_robot_ore_cost, obsidian_robot_clay_cost)) = delimited(
             tag("Each obsidian robot costs "),
-            separated_pair(u64, tag(" ore and "), u64),
+            separated_pair(u32, tag(" ore and "), u32),
             tag(" clay."),
         )(s)?;
         let (s, _) = char(' ')(s)?;
         let (s, (geode_robot_ore_cost, geode_robot_obsidian_cost)) = delimited(
             tag("Each geode robot costs "),
-            separated_pair(u64, tag(" ore and "), u64),
+            separated_pair(u32, tag(" ore and "), u32),
             tag(" obsidian."),
         )(s)?;
 
@@ -369,18 +477,70 @@ mod parser {
     }
 }
 
-#[cfg(test)]
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` blueprints, at least one, with costs in the ranges of the real puzzle inputs.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|id| {
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                    rng.range(2..=4),
+                    rng.range(7..=20)
+                )
+            })
+            .collect()
+    }
+}
+
 static EXAMPLE: &str = "\
 Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
 Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
 ";
 
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("33"),
+    part_2: Some("3472"),
+    params: &[],
+}];
+
 #[test]
 fn part_1_example() {
-    assert_eq!(part_1(EXAMPLE), 33);
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 33);
 }
 
 #[test]
 fn part_2_example() {
-    assert_eq!(part_2(EXAMPLE), 56 * 62);
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 56 * 62);
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(
+        0..20,
+        |rng| generator::generate(rng, 10),
+        parser::parse,
+        |blueprints| blueprints.iter().map(Blueprint::to_string).collect::<Vec<_>>().join("\n"),
+    );
+}
+
+#[test]
+fn params_reject_negative_minutes() {
+    assert_eq!(<Params as params::Params>::with(&[("part_1_minutes", "0")]).unwrap().part_1_minutes, 0);
+    assert_eq!(
+        <Params as params::Params>::with(&[("part_2_minutes", "-1")]),
+        Err(ParamError::Value {
+            name: String::from("part_2_minutes"),
+            value: String::from("-1"),
+            reason: String::from("minutes cannot be negative"),
+        })
+    );
 }
@@ -0,0 +1,245 @@
+//! Day 20: Grove Positioning System
+//!
+//! https://adventofcode.com/2022/day/20
+
+use crate::cancel;
+use crate::error::PuzzleError;
+use crate::params;
+use crate::params::ParamError;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+
+pub fn part_1(numbers: &[i64]) -> Result<i64, PuzzleError> {
+    let mixed = mix_numbers(numbers, 1)?;
+    sum_of_grove_coordinates(&mixed)
+}
+
+pub fn part_2(numbers: &[i64], params: &Params) -> Result<i64, PuzzleError> {
+    let numbers: Vec<i64> = numbers
+        .iter()
+        .map(|&n| {
+            n.checked_mul(params.decryption_key)
+                .ok_or_else(|| PuzzleError::unsolvable(format!("decrypting {n} overflows")))
+        })
+        .collect::<Result<_, _>>()?;
+    log::debug!("Decrypted numbers: {numbers:?}");
+    let mixed = mix_numbers(&numbers, params.rounds)?;
+    sum_of_grove_coordinates(&mixed)
+}
+
+/// Problems with the numbers, which must include exactly one 0 to find the grove coordinates from.
+///
+/// Several zeros are fine if all numbers are 0, as the grove coordinates are then the same from any of them.
+pub fn validate(numbers: &[i64]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if numbers.len() < 2 {
+        problems.push(String::from("there must be at least two numbers to mix"));
+    }
+    match numbers.iter().filter(|&&n| n == 0).count() {
+        0 => problems.push(String::from("there is no number 0")),
+        zeros if zeros > 1 && zeros < numbers.len() => problems.push(format!("there are {zeros} numbers 0, expected exactly one")),
+        _ => {}
+    }
+    problems
+}
+
+pub struct Day20;
+impl Solution for Day20 {
+    type Input<'i> = Vec<i64>;
+    type Params = Params;
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Grove Positioning System"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input, params).map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Key that the numbers are multiplied by in part 2.
+    pub decryption_key: i64,
+
+    /// Number of times the numbers are mixed in part 2.
+    pub rounds: usize,
+}
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            decryption_key: 811_589_153,
+            rounds: 10,
+        }
+    }
+}
+impl params::Params for Params {
+    const NAMES: &'static [&'static str] = &["decryption_key", "rounds"];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "decryption_key" => self.decryption_key = params::parse(name, value)?,
+            "rounds" => self.rounds = params::parse(name, value)?,
+            _ => return Err(params::unknown::<Self>(name)),
+        }
+        Ok(())
+    }
+}
+
+fn sum_of_grove_coordinates(mixed: &[i64]) -> Result<i64, PuzzleError> {
+    grove_coordinates(mixed)
+        .into_iter()
+        .try_fold(0, i64::checked_add)
+        .ok_or_else(|| PuzzleError::unsolvable("the sum of the grove coordinates overflows"))
+}
+
+fn grove_coordinates(mixed: &[i64]) -> [i64; 3] {
+    let mixed_index_of_zero = mixed.iter().enumerate().find(|&(_, &n)| n == 0).map(|(idx, _)| idx).unwrap();
+
+    let x = {
+        let i = (mixed_index_of_zero + 1000).rem_euclid(mixed.len());
+        mixed[i]
+    };
+
+    let y = {
+        let i = (mixed_index_of_zero + 2000).rem_euclid(mixed.len());
+        mixed[i]
+    };
+
+    let z = {
+        let i = (mixed_index_of_zero + 3000).rem_euclid(mixed.len());
+        mixed[i]
+    };
+
+    [x, y, z]
+}
+
+fn mix_numbers(numbers: &[i64], rounds: usize) -> Result<Vec<i64>, PuzzleError> {
+    let mut map_from_mixed_index_to_original_index: Vec<usize> = (0..).take(numbers.len()).collect();
+
+    for _ in 0..rounds {
+        for (original_index, n) in numbers.iter().enumerate() {
+            cancel::check()?;
+            let mixed_index = map_from_mixed_index_to_original_index
+                .iter()
+                .enumerate()
+                .find(|&(_, &orig_idx)| original_index == orig_idx)
+                .map(|(idx, _)| idx)
+                .unwrap();
+            // Reduce `n` first, so that large numbers cannot overflow.
+            let cycle = numbers.len() as i64 - 1;
+            let new_mixed_index = (mixed_index as i64 + n.rem_euclid(cycle)).rem_euclid(cycle);
+            debug_assert!(new_mixed_index >= 0);
+
+            let i = map_from_mixed_index_to_original_index.remove(mixed_index);
+            debug_assert_eq!(original_index, i);
+            map_from_mixed_index_to_original_index.insert(new_mixed_index as usize, i);
+        }
+    }
+
+    let mut mixed = vec![0; numbers.len()];
+    for (mixed_index, original_index) in map_from_mixed_index_to_original_index.iter().enumerate() {
+        mixed[mixed_index] = numbers[*original_index];
+    }
+    Ok(mixed)
+}
+
+pub mod parser {
+    use crate::error::PuzzleError;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<i64>, PuzzleError> {
+        parse_all(s, terminated(separated_list1(line_ending, main_parser), multispace0))
+    }
+
+    fn main_parser(s: &str) -> IResult<&str, i64> {
+        i64(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+
+    /// Random list of `size` numbers, at least two, of which exactly one is 0.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers: Vec<i64> = (1..size.max(2))
+            .map(|_| {
+                let n = rng.range(1..=10_000);
+                if rng.chance(1, 2) { n } else { -n }
+            })
+            .collect();
+        numbers.insert(rng.range(0..=numbers.len()), 0);
+        numbers.iter().map(|n| format!("{n}\n")).collect()
+    }
+}
+
+static EXAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4
+";
+
+static EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: EXAMPLE,
+    part_1: Some("3"),
+    part_2: Some("1623178306"),
+    params: &[],
+}];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 4 - 3 + 2);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap(), &Params::default()).unwrap(), 1623178306);
+}
+
+#[test]
+fn test_validate() {
+    assert!(validate(&parser::parse(EXAMPLE).unwrap()).is_empty());
+    assert_eq!(validate(&[0, 1, 0]), vec![String::from("there are 2 numbers 0, expected exactly one")]);
+    assert!(validate(&[0, 0, 0]).is_empty());
+    assert_eq!(
+        validate(&[1]),
+        vec![
+            String::from("there must be at least two numbers to mix"),
+            String::from("there is no number 0")
+        ]
+    );
+}
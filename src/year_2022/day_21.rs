@@ -0,0 +1,823 @@
+//! Day 21: Monkey Math
+//!
+//! https://adventofcode.com/2022/day/21
+
+use crate::error::PuzzleError;
+use crate::lib::graph;
+use crate::lib::graph::Graph;
+use crate::lib::graph::GraphEdge;
+use crate::rng::Rng;
+use crate::solution::Example;
+use crate::solution::Solution;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub fn part_1(monkeys: &[Monkey]) -> Result<i64, PuzzleError> {
+    // Map name of monkey to its job
+    let monkey_map: HashMap<String, Monkey> = monkeys.iter().map(|monkey| (monkey.name.clone(), monkey.clone())).collect();
+    let monkey_graph = MonkeyGraph { monkey_map };
+    let root = monkey_graph
+        .monkey_map
+        .get("root")
+        .ok_or_else(|| PuzzleError::invalid("there is no root"))?;
+
+    let topologically_sorted_monkeys = graph::topological_sort::topological_sort(&monkey_graph, root);
+    debug_assert_eq!(topologically_sorted_monkeys.last().unwrap().name, "root");
+
+    let mut monkey_yell = HashMap::<&str, i64>::new();
+    for monkey in topologically_sorted_monkeys.iter() {
+        let n = match &monkey.job {
+            Job::SpecificNumber(n) => *n,
+            Job::MathOperation {
+                monkey_1,
+                operator,
+                monkey_2,
+            } => {
+                // Monkeys come after those they wait for, unless they wait for themselves or for unknown monkeys.
+                let yell = |name: &str| {
+                    monkey_yell.get(name).copied().ok_or_else(|| {
+                        if monkey_graph.monkey_map.contains_key(name) {
+                            PuzzleError::invalid(format!("monkey {} waits for itself", monkey.name))
+                        } else {
+                            PuzzleError::invalid(format!("{} waits for unknown monkey {name}", monkey.name))
+                        }
+                    })
+                };
+                operator.monkey_math(yell(monkey_1)?, yell(monkey_2)?)?
+            }
+        };
+        monkey_yell.insert(&monkey.name, n);
+    }
+
+    Ok(monkey_yell["root"])
+}
+
+pub fn part_2(monkeys: &[Monkey]) -> Result<i64, PuzzleError> {
+    let monkeys: HashMap<String, Monkey> = monkeys.iter().map(|monkey| (monkey.name.clone(), monkey.clone())).collect();
+
+    if !monkeys.contains_key("humn") {
+        return Err(PuzzleError::invalid("there is no humn"));
+    }
+
+    let root = monkeys.get("root").ok_or_else(|| PuzzleError::invalid("there is no root"))?;
+    let Job::MathOperation { monkey_1, monkey_2, .. } = &root.job else {
+        return Err(PuzzleError::invalid("root must have a math operation"));
+    };
+    let monkey_1 = waited_for(&monkeys, root, monkey_1)?;
+    let monkey_2 = waited_for(&monkeys, root, monkey_2)?;
+
+    // Descending fails on monkeys that wait for themselves, so the other descents below only follow monkeys that do not.
+    let mut cache = HashMap::<&Monkey, i64>::new();
+    let mut waiting = HashSet::new();
+    let monkey_1_n: Option<i64> = cached_descend(&monkeys, monkey_1, &mut cache, &mut waiting, Some("humn"))?;
+    let monkey_2_n: Option<i64> = cached_descend(&monkeys, monkey_2, &mut cache, &mut waiting, Some("humn"))?;
+    let (expected_result, indeterminate_monkey) = match (monkey_1_n, monkey_2_n) {
+        (None, Some(n)) => (n, monkey_1),
+        (Some(n), None) => (n, monkey_2),
+        (Some(_), Some(_)) => return Err(PuzzleError::unsolvable("what humn yells does not matter to root")),
+        (None, None) => return Err(PuzzleError::unsolvable("both monkeys of root depend on humn")),
+    };
+
+    let humn_yell = what_should_humn_yell_to_make_this_monkey_yell_n(&monkeys, &cache, indeterminate_monkey, expected_result)?;
+
+    #[cfg(debug_assertions)]
+    {
+        let mut monkeys = monkeys.clone();
+        let humn = monkeys.get_mut("humn").unwrap();
+        humn.job = Job::SpecificNumber(humn_yell);
+        let monkey_1 = &monkeys[&monkey_1.name];
+        let monkey_2 = &monkeys[&monkey_2.name];
+
+        let yell_override = ("humn", humn_yell);
+        let monkey_1_yell = brute_descend(&monkeys, monkey_1, Some(yell_override)).unwrap();
+        let monkey_2_yell = brute_descend(&monkeys, monkey_2, Some(yell_override)).unwrap();
+
+        debug_assert_eq!(monkey_1_yell, monkey_2_yell);
+    }
+
+    Ok(humn_yell)
+}
+
+/// Problems with the monkeys, which must all wait for known monkeys but not for themselves, and include `root` with a math
+/// operation and `humn`.
+pub fn validate(monkeys: &[Monkey]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let names: HashSet<&str> = monkeys.iter().map(|monkey| monkey.name.as_str()).collect();
+    for monkey in monkeys {
+        if let Job::MathOperation { monkey_1, monkey_2, .. } = &monkey.job {
+            for unknown in [monkey_1, monkey_2].into_iter().filter(|name| !names.contains(name.as_str())) {
+                problems.push(format!("{} waits for unknown monkey {unknown}", monkey.name));
+            }
+        }
+    }
+    for name in monkeys_waiting_for_themselves(monkeys) {
+        problems.push(format!("monkey {name} waits for itself"));
+    }
+    match monkeys.iter().find(|monkey| monkey.name == "root") {
+        Some(Monkey {
+            job: Job::MathOperation { .. },
+            ..
+        }) => {}
+        Some(_) => problems.push(String::from("root must have a math operation")),
+        None => problems.push(String::from("there is no root")),
+    }
+    if !names.contains("humn") {
+        problems.push(String::from("there is no humn"));
+    }
+    problems
+}
+
+/// Monkeys that close a cycle of monkeys waiting for each other.
+///
+/// Depth-first search that colours monkeys grey while following who they wait for, and black once all of those are
+/// done. Reaching a grey monkey again means that it waits for itself.
+fn monkeys_waiting_for_themselves(monkeys: &[Monkey]) -> Vec<&str> {
+    let waits_for: HashMap<&str, Vec<&str>> = monkeys
+        .iter()
+        .map(|monkey| match &monkey.job {
+            Job::SpecificNumber(_) => (monkey.name.as_str(), vec![]),
+            Job::MathOperation { monkey_1, monkey_2, .. } => (monkey.name.as_str(), vec![monkey_1.as_str(), monkey_2.as_str()]),
+        })
+        .collect();
+
+    let mut grey = HashSet::new();
+    let mut black = HashSet::new();
+    let mut cyclic = Vec::new();
+    for monkey in monkeys {
+        if black.contains(monkey.name.as_str()) {
+            continue;
+        }
+        // Grey monkeys, with how many of the monkeys they wait for have been followed.
+        let mut stack = vec![(monkey.name.as_str(), 0)];
+        grey.insert(monkey.name.as_str());
+        while let Some((name, followed)) = stack.last_mut() {
+            let name = *name;
+            let Some(&other) = waits_for[name].get(*followed) else {
+                grey.remove(name);
+                black.insert(name);
+                stack.pop();
+                continue;
+            };
+            *followed += 1;
+
+            if grey.contains(other) {
+                if !cyclic.contains(&other) {
+                    cyclic.push(other);
+                }
+            } else if !black.contains(other) && waits_for.contains_key(other) {
+                grey.insert(other);
+                stack.push((other, 0));
+            }
+        }
+    }
+    cyclic
+}
+
+pub struct Day21;
+impl Solution for Day21 {
+    type Input<'i> = Vec<Monkey>;
+    type Params = ();
+
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Monkey Math"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+
+    fn parse<'i>(&self, input: &'i str) -> Result<Self::Input<'i>, PuzzleError> {
+        parser::parse(input)
+    }
+
+    fn validate(&self, input: &Self::Input<'_>) -> Vec<String> {
+        validate(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        generator::generate(rng, size)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_1(input).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>, _params: &Self::Params) -> Result<String, PuzzleError> {
+        part_2(input).map(|answer| answer.to_string())
+    }
+}
+
+fn what_should_humn_yell_to_make_this_monkey_yell_n(
+    monkeys: &HashMap<String, Monkey>,
+    cache: &HashMap<&Monkey, i64>,
+    monkey: &Monkey,
+    n: i64,
+) -> Result<i64, PuzzleError> {
+    if monkey.name == "humn" {
+        return Ok(n);
+    }
+
+    let humn_should_yell = match &monkey.job {
+        Job::SpecificNumber(_) => unreachable!("monkeys that yell a specific number do not depend on humn"),
+        Job::MathOperation {
+            monkey_1,
+            operator,
+            monkey_2,
+        } => {
+            let monkey_1 = waited_for(monkeys, monkey, monkey_1)?;
+            let monkey_2 = waited_for(monkeys, monkey, monkey_2)?;
+            let monkey_1_yell = cache.get(monkey_1).copied();
+            let monkey_2_yell = cache.get(monkey_2).copied();
+
+            let (unknown_monkey, unknown_yell) = match (monkey_1_yell, operator, monkey_2_yell) {
+                (None, Operator::Add, Some(x)) => (monkey_1, n.checked_sub(x)),
+                (None, Operator::Subtract, Some(x)) => (monkey_1, n.checked_add(x)),
+                (None, Operator::Multiply, Some(x)) => (monkey_1, n.checked_div(x)),
+                (None, Operator::Divide, Some(x)) => (monkey_1, n.checked_mul(x)),
+                (Some(x), Operator::Add, None) => (monkey_2, n.checked_sub(x)),
+                (Some(x), Operator::Subtract, None) => (monkey_2, x.checked_sub(n)),
+                (Some(x), Operator::Multiply, None) => (monkey_2, n.checked_div(x)),
+                (Some(x), Operator::Divide, None) => (monkey_2, x.checked_div(n)),
+
+                (Some(_), _, Some(_)) => unreachable!("one of the monkeys depends on humn"),
+                (None, _, None) => {
+                    // This might be pretty hard to figure out, as neither branch is known
+                    return Err(PuzzleError::unsolvable(format!("both monkeys of {} depend on humn", monkey.name)));
+                }
+            };
+
+            // Division rounds, so the yell is only right if the math works out the other way around.
+            let unsolvable = || {
+                PuzzleError::unsolvable(format!(
+                    "{} cannot yell a number that makes {} yell {n}",
+                    unknown_monkey.name, monkey.name
+                ))
+            };
+            let unknown_yell = unknown_yell.ok_or_else(unsolvable)?;
+            if operator.monkey_math(monkey_1_yell.unwrap_or(unknown_yell), monkey_2_yell.unwrap_or(unknown_yell)) != Ok(n) {
+                return Err(unsolvable());
+            }
+            let humn_should_yell = what_should_humn_yell_to_make_this_monkey_yell_n(monkeys, cache, unknown_monkey, unknown_yell)?;
+
+            #[cfg(debug_assertions)]
+            {
+                let yell_override = ("humn", humn_should_yell);
+                let monkey_1_yell = brute_descend(monkeys, monkey_1, Some(yell_override)).unwrap();
+                let monkey_2_yell = brute_descend(monkeys, monkey_2, Some(yell_override)).unwrap();
+                let what_would_this_monkey_yell = operator.monkey_math(monkey_1_yell, monkey_2_yell);
+                debug_assert_eq!(what_would_this_monkey_yell, Ok(n));
+            }
+
+            humn_should_yell
+        }
+    };
+
+    Ok(humn_should_yell)
+}
+
+/// What a monkey yells, or nothing if it waits for `abort_if_name`.
+///
+/// Fails if the monkey waits for one of the `waiting` monkeys that are being descended from, as it then waits for itself.
+fn cached_descend<'g>(
+    monkeys: &'g HashMap<String, Monkey>,
+    monkey: &'g Monkey,
+    cache: &mut HashMap<&'g Monkey, i64>,
+    waiting: &mut HashSet<&'g str>,
+    abort_if_name: Option<&str>,
+) -> Result<Option<i64>, PuzzleError> {
+    if Some(monkey.name.as_str()) == abort_if_name {
+        return Ok(None);
+    }
+    if !waiting.insert(&monkey.name) {
+        return Err(PuzzleError::invalid(format!("monkey {} waits for itself", monkey.name)));
+    }
+    let yell = match &monkey.job {
+        Job::SpecificNumber(n) => Some(*n),
+        Job::MathOperation {
+            monkey_1,
+            operator,
+            monkey_2,
+        } => {
+            let monkey_1 = waited_for(monkeys, monkey, monkey_1)?;
+            let monkey_2 = waited_for(monkeys, monkey, monkey_2)?;
+
+            let monkey_1_yell = match cache.get(monkey_1) {
+                Some(&yell) => Some(yell),
+                None => cached_descend(monkeys, monkey_1, cache, waiting, abort_if_name)?,
+            };
+            let monkey_2_yell = match cache.get(monkey_2) {
+                Some(&yell) => Some(yell),
+                None => cached_descend(monkeys, monkey_2, cache, waiting, abort_if_name)?,
+            };
+
+            match (monkey_1_yell, monkey_2_yell) {
+                (Some(monkey_1_yell), Some(monkey_2_yell)) => Some(operator.monkey_math(monkey_1_yell, monkey_2_yell)?),
+                _ => None,
+            }
+        }
+    };
+    waiting.remove(monkey.name.as_str());
+    if let Some(yell) = yell {
+        cache.insert(monkey, yell);
+    }
+    Ok(yell)
+}
+
+/// The monkey with the given name, that `monkey` waits for.
+fn waited_for<'g>(monkeys: &'g HashMap<String, Monkey>, monkey: &Monkey, name: &str) -> Result<&'g Monkey, PuzzleError> {
+    monkeys
+        .get(name)
+        .ok_or_else(|| PuzzleError::invalid(format!("{} waits for unknown monkey {name}", monkey.name)))
+}
+
+// No caching, and ability to override
+#[cfg(debug_assertions)]
+fn brute_descend<'g>(monkeys: &'g HashMap<String, Monkey>, monkey: &'g Monkey, yell_override: Option<(&str, i64)>) -> Option<i64> {
+    if let Some((name, yell)) = yell_override {
+        if name == monkey.name {
+            return Some(yell);
+        }
+    }
+
+    let yell = match &monkey.job {
+        Job::SpecificNumber(n) => *n,
+        Job::MathOperation {
+            monkey_1,
+            operator,
+            monkey_2,
+        } => {
+            let monkey_1 = monkeys.get(monkey_1)?;
+            let monkey_2 = monkeys.get(monkey_2)?;
+
+            let monkey_1_yell = brute_descend(monkeys, monkey_1, yell_override);
+            let monkey_2_yell = brute_descend(monkeys, monkey_2, yell_override);
+
+            let monkey_1_yell = monkey_1_yell?;
+            let monkey_2_yell = monkey_2_yell?;
+
+            operator.monkey_math(monkey_1_yell, monkey_2_yell).ok()?
+        }
+    };
+    Some(yell)
+}
+
+struct MonkeyGraph {
+    monkey_map: HashMap<String, Monkey>,
+}
+
+impl<'g> Graph<'g> for MonkeyGraph {
+    type Node = Monkey;
+    type Edge = &'g Monkey;
+
+    fn edges(&'g self, from: &Self::Node) -> Vec<Self::Edge> {
+        match &from.job {
+            Job::SpecificNumber(_) => vec![],
+            Job::MathOperation {
+                monkey_1,
+                operator: _,
+                monkey_2,
+            } => [monkey_1, monkey_2].into_iter().filter_map(|name| self.monkey_map.get(name)).collect(),
+        }
+    }
+}
+
+impl<'g> GraphEdge<'g> for &'g Monkey {
+    type Node = Monkey;
+
+    fn to(&self) -> std::borrow::Cow<'g, Self::Node> {
+        Cow::Borrowed(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Monkey {
+    name: String,
+    job: Job,
+}
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.job {
+            Job::SpecificNumber(n) => write!(f, "{}: {n}", self.name),
+            Job::MathOperation {
+                monkey_1,
+                operator,
+                monkey_2,
+            } => write!(f, "{}: {monkey_1} {operator} {monkey_2}", self.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Job {
+    SpecificNumber(i64),
+    MathOperation {
+        monkey_1: String,
+        operator: Operator,
+        monkey_2: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+impl Operator {
+    /// Fails on division by zero, and on yells that do not fit in an `i64`.
+    fn monkey_math(&self, monkey_1_yell: i64, monkey_2_yell: i64) -> Result<i64, PuzzleError> {
+        let yell = match self {
+            Operator::Add => monkey_1_yell.checked_add(monkey_2_yell),
+            Operator::Subtract => monkey_1_yell.checked_sub(monkey_2_yell),
+            Operator::Multiply => monkey_1_yell.checked_mul(monkey_2_yell),
+            Operator::Divide => monkey_1_yell.checked_div(monkey_2_yell),
+        };
+        yell.ok_or_else(|| PuzzleError::unsolvable(format!("a monkey cannot yell {monkey_1_yell} {self} {monkey_2_yell}")))
+    }
+}
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Operator::Add => '+',
+            Operator::Subtract => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+pub mod parser {
+    use super::*;
+    use crate::nom_complete::*;
+
+    pub fn parse(s: &str) -> Result<Vec<Monkey>, PuzzleError> {
+        parse_all(s, terminated(separated_list1(line_ending, parse_monkey), multispace0))
+    }
+
+    fn parse_monkey(s: &str) -> IResult<&str, Monkey> {
+        let (s, name) = terminated(alpha1, tag(": "))(s)?;
+
+        let (s, job) = alt((parse_job_specific, parse_job_operation))(s)?;
+
+        let monkey = Monkey { name: name.to_owned(), job };
+        Ok((s, monkey))
+    }
+
+    fn parse_job_specific(s: &str) -> IResult<&str, Job> {
+        map(i64, Job::SpecificNumber)(s)
+    }
+
+    fn parse_job_operation(s: &str) -> IResult<&str, Job> {
+        let (s, monkey_1) = alpha1(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, operator) = parse_operator(s)?;
+        let (s, _) = char(' ')(s)?;
+        let (s, monkey_2) = alpha1(s)?;
+
+        let job = Job::MathOperation {
+            monkey_1: monkey_1.to_owned(),
+            operator,
+            monkey_2: monkey_2.to_owned(),
+        };
+        Ok((s, job))
+    }
+
+    fn parse_operator(s: &str) -> IResult<&str, Operator> {
+        let add = value(Operator::Add, char('+'));
+        let subtract = value(Operator::Subtract, char('-'));
+        let multiply = value(Operator::Multiply, char('*'));
+        let divide = value(Operator::Divide, char('/'));
+        alt((add, subtract, multiply, divide))(s)
+    }
+}
+
+pub mod generator {
+    use crate::rng::Rng;
+    use std::collections::HashSet;
+
+    /// Random list of about `size` monkeys, in which `humn` is on a path of monkeys that one side of `root` waits for.
+    ///
+    /// The path is built up from what `humn` should yell in part 2, such that every division along it is exact.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut generator = Generator {
+            rng,
+            names: HashSet::from([String::from("root"), String::from("humn")]),
+            lines: Vec::new(),
+        };
+        let n_steps = (size / 4).max(1);
+        let budget = (size / n_steps).saturating_sub(1).max(1);
+
+        let mut name = String::from("humn");
+        let mut value: i64 = generator.rng.range(1..=10_000);
+        // How much the value changes for every change of what `humn` yells, to keep part 1 from overflowing.
+        let mut slope = 1.0;
+        for _ in 0..n_steps {
+            let (operator, constant, next_value, humn_first) = loop {
+                let constant: i64 = generator.rng.range(2..=5);
+                match generator.rng.range(0..=3) {
+                    0 => {
+                        let constant = generator.rng.range(1..=1000);
+                        break ('+', constant, value + constant, generator.rng.chance(1, 2));
+                    }
+                    1 => {
+                        let constant = generator.rng.range(1..=1000);
+                        if generator.rng.chance(1, 2) {
+                            break ('-', constant, value - constant, true);
+                        }
+                        break ('-', constant, constant - value, false);
+                    }
+                    2 if value.abs() < 1_000_000_000_000 && slope * 1e4 < 1e12 => {
+                        slope *= constant as f64;
+                        break ('*', constant, value * constant, generator.rng.chance(1, 2));
+                    }
+                    3 if value % constant == 0 => {
+                        slope /= constant as f64;
+                        break ('/', constant, value / constant, true);
+                    }
+                    _ => {}
+                }
+            };
+            let constant_name = generator.constant(constant, budget);
+            let next_name = generator.name();
+            if humn_first {
+                generator.lines.push(format!("{next_name}: {name} {operator} {constant_name}"));
+            } else {
+                generator.lines.push(format!("{next_name}: {constant_name} {operator} {name}"));
+            }
+            (name, value) = (next_name, next_value);
+        }
+
+        let constant_name = generator.constant(value, budget);
+        let operator = generator.rng.choose(&['+', '-']);
+        if generator.rng.chance(1, 2) {
+            generator.lines.push(format!("root: {name} {operator} {constant_name}"));
+        } else {
+            generator.lines.push(format!("root: {constant_name} {operator} {name}"));
+        }
+        let humn = generator.rng.range(1..=10_000);
+        generator.lines.push(format!("humn: {humn}"));
+
+        generator.rng.shuffle(&mut generator.lines);
+        generator.lines.join("\n") + "\n"
+    }
+
+    struct Generator<'a> {
+        rng: &'a mut Rng,
+
+        /// Names that are taken.
+        names: HashSet<String>,
+        lines: Vec<String>,
+    }
+    impl Generator<'_> {
+        /// Unique name of four letters for a new monkey.
+        fn name(&mut self) -> String {
+            loop {
+                let name: String = (0..4).map(|_| self.rng.range(b'a'..=b'z') as char).collect();
+                if self.names.insert(name.clone()) {
+                    return name;
+                }
+            }
+        }
+
+        /// Name of a new monkey that yells `target`, after waiting for about `budget` other monkeys.
+        ///
+        /// Only monkeys that do not wait for others yell a specific number, which is never negative.
+        fn constant(&mut self, target: i64, budget: usize) -> String {
+            let name = self.name();
+            if budget <= 1 && target >= 0 {
+                self.lines.push(format!("{name}: {target}"));
+                return name;
+            }
+
+            let budget = budget.saturating_sub(1).max(2);
+            let (value_1, operator, value_2) = loop {
+                match self.rng.range(0..=3) {
+                    0 if target >= 0 => {
+                        let value_1 = self.rng.range(0..=target);
+                        break (value_1, '+', target - value_1);
+                    }
+                    1 => {
+                        let value_2 = self.rng.range(0..=100) - target.min(0);
+                        break (target + value_2, '-', value_2);
+                    }
+                    2 if target != 0 => {
+                        let divisor = self.rng.range(2..=9);
+                        if target % divisor == 0 {
+                            break (divisor, '*', target / divisor);
+                        }
+                    }
+                    3 if target.abs() < 1_000_000_000_000 => {
+                        let divisor = self.rng.range(2..=5);
+                        break (target * divisor, '/', divisor);
+                    }
+                    _ => {}
+                }
+            };
+            let budget_1 = self.rng.range(1..=budget - 1);
+            let monkey_1 = self.constant(value_1, budget_1);
+            let monkey_2 = self.constant(value_2, budget - budget_1);
+            self.lines.push(format!("{name}: {monkey_1} {operator} {monkey_2}"));
+            name
+        }
+    }
+}
+
+/// Brute-force solutions, which follow the monkeys by name and try every number for `humn` to yell.
+#[cfg(test)]
+mod reference {
+    use super::*;
+    use std::ops::RangeInclusive;
+
+    /// What a monkey yells, with `humn` yelling the given number instead of its own.
+    ///
+    /// While trying numbers for `humn`, only exact divisions can be part of the answer, so others yell nothing.
+    fn yell(jobs: &HashMap<&str, &Job>, name: &str, humn: Option<i64>) -> Option<i64> {
+        match jobs[name] {
+            _ if name == "humn" && humn.is_some() => humn,
+            Job::SpecificNumber(n) => Some(*n),
+            Job::MathOperation {
+                monkey_1,
+                operator,
+                monkey_2,
+            } => {
+                let monkey_1_yell = yell(jobs, monkey_1, humn)?;
+                let monkey_2_yell = yell(jobs, monkey_2, humn)?;
+                if *operator == Operator::Divide && humn.is_some() && monkey_1_yell.checked_rem(monkey_2_yell) != Some(0) {
+                    return None;
+                }
+                operator.monkey_math(monkey_1_yell, monkey_2_yell).ok()
+            }
+        }
+    }
+
+    fn jobs(monkeys: &[Monkey]) -> HashMap<&str, &Job> {
+        monkeys.iter().map(|monkey| (monkey.name.as_str(), &monkey.job)).collect()
+    }
+
+    pub fn part_1(monkeys: &[Monkey]) -> i64 {
+        yell(&jobs(monkeys), "root", None).unwrap()
+    }
+
+    /// The first of the given numbers for `humn` to yell that makes both monkeys of `root` yell the same.
+    pub fn part_2(monkeys: &[Monkey], candidates: RangeInclusive<i64>) -> Option<i64> {
+        let jobs = jobs(monkeys);
+        let Job::MathOperation { monkey_1, monkey_2, .. } = jobs["root"] else {
+            return None;
+        };
+        candidates.into_iter().find(|&humn| {
+            let monkey_1_yell = yell(&jobs, monkey_1, Some(humn));
+            monkey_1_yell.is_some() && monkey_1_yell == yell(&jobs, monkey_2, Some(humn))
+        })
+    }
+}
+
+static EXAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
+
+static ADDITIONAL_EXAMPLE_PART_2: &str = "\
+root: juli + josi
+juli: amee + alex
+amee: buki * abby
+buki: 5
+abby: 4
+alex: 4
+josi: benj / mark
+benj: 360
+mark: emly - humn
+emly: 34
+humn: 0
+";
+
+static EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: EXAMPLE,
+        part_1: Some("152"),
+        part_2: Some("301"),
+        params: &[],
+    },
+    Example {
+        name: "additional_example",
+        input: ADDITIONAL_EXAMPLE_PART_2,
+        part_1: None,
+        part_2: Some("19"),
+        params: &[],
+    },
+];
+
+#[test]
+fn part_1_example() {
+    assert_eq!(part_1(&parser::parse(EXAMPLE).unwrap()).unwrap(), 152);
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(part_2(&parser::parse(EXAMPLE).unwrap()).unwrap(), 301);
+}
+
+#[test]
+fn part_2_additional_example() {
+    assert_eq!(part_2(&parser::parse(ADDITIONAL_EXAMPLE_PART_2).unwrap()).unwrap(), 19);
+}
+
+#[test]
+fn test_validate() {
+    assert!(validate(&parser::parse(EXAMPLE).unwrap()).is_empty());
+    let monkeys = parser::parse("root: 5\nabcd: efgh + humx\n").unwrap();
+    assert_eq!(
+        validate(&monkeys),
+        vec![
+            String::from("abcd waits for unknown monkey efgh"),
+            String::from("abcd waits for unknown monkey humx"),
+            String::from("root must have a math operation"),
+            String::from("there is no humn"),
+        ]
+    );
+    let monkeys = parser::parse("root: aaaa + bbbb\naaaa: root + bbbb\nbbbb: 1\nhumn: 1\n").unwrap();
+    assert_eq!(validate(&monkeys), vec![String::from("monkey root waits for itself")]);
+}
+
+#[test]
+fn agrees_with_reference() {
+    crate::differential::assert_agree(0..30, |rng| {
+        let monkeys = parser::parse(&generator::generate(rng, 20)).unwrap();
+        (
+            (part_1(&monkeys).unwrap(), part_2(&monkeys).ok()),
+            (reference::part_1(&monkeys), reference::part_2(&monkeys, 0..=10_000)),
+        )
+    });
+}
+
+#[test]
+fn display_round_trips() {
+    crate::differential::assert_round_trips(
+        0..20,
+        |rng| generator::generate(rng, 20),
+        parser::parse,
+        |monkeys| monkeys.iter().map(Monkey::to_string).collect::<Vec<_>>().join("\n"),
+    );
+}
+
+#[test]
+fn part_1_fails_on_division_by_zero() {
+    let monkeys = parser::parse("root: humn / zero\nhumn: 5\nzero: 0\n").unwrap();
+    assert!(part_1(&monkeys).is_err());
+}
+
+#[test]
+fn parts_fail_on_unknown_monkeys() {
+    let monkeys = parser::parse("root: humn + abcd\nhumn: 5\n").unwrap();
+    let expected = Err(PuzzleError::invalid("root waits for unknown monkey abcd"));
+    assert_eq!(part_1(&monkeys), expected);
+    assert_eq!(part_2(&monkeys), expected);
+    assert_eq!(
+        part_1(&parser::parse("humn: 5\n").unwrap()),
+        Err(PuzzleError::invalid("there is no root"))
+    );
+}
+
+#[test]
+fn parts_fail_if_a_monkey_waits_for_itself() {
+    let monkeys = parser::parse("root: aaaa + bbbb\naaaa: root + bbbb\nbbbb: 1\nhumn: 1\n").unwrap();
+    assert_eq!(part_1(&monkeys), Err(PuzzleError::invalid("monkey aaaa waits for itself")));
+    assert_eq!(part_2(&monkeys), Err(PuzzleError::invalid("monkey aaaa waits for itself")));
+
+    let monkeys = parser::parse("root: humn + aaaa\naaaa: bbbb * cccc\nbbbb: aaaa - cccc\ncccc: 2\nhumn: 1\n").unwrap();
+    assert_eq!(part_2(&monkeys), Err(PuzzleError::invalid("monkey aaaa waits for itself")));
+}
+
+#[test]
+fn part_2_fails_if_division_does_not_work_out() {
+    let monkeys = parser::parse("root: left + seven\nleft: humn * three\nthree: 3\nseven: 7\nhumn: 1\n").unwrap();
+    assert_eq!(
+        part_2(&monkeys),
+        Err(PuzzleError::unsolvable("humn cannot yell a number that makes left yell 7"))
+    );
+}